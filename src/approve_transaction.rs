@@ -0,0 +1,115 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decoding and checks of the approval PTB sent to key servers.
+//!
+//! Key servers only accept PTBs made exclusively of `seal_approve*` move calls into a single
+//! package, each taking the requested id as a pure first argument. The client runs the same
+//! checks before any request so a malformed PTB fails with a typed error instead of a key
+//! server rejection.
+
+use crate::error::ApproveTransactionError;
+use crate::generic_types::ObjectID;
+use sui_sdk_types::{Argument, Command, Input, ProgrammableTransaction};
+
+/// Prefix of the functions key servers accept in an approval PTB.
+pub const SEAL_APPROVE_PREFIX: &str = "seal_approve";
+
+/// One `seal_approve*` call of an approval PTB.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SealApproveCall {
+    pub package_id: ObjectID,
+    pub module: String,
+    pub function: String,
+    /// Requested id, without the package prefix.
+    pub id: Vec<u8>,
+}
+
+/// Decode a BCS-encoded approval PTB into its `seal_approve*` calls, rejecting any PTB a key
+/// server would refuse.
+pub fn seal_approve_calls(ptb: &[u8]) -> Result<Vec<SealApproveCall>, ApproveTransactionError> {
+    let ptb: ProgrammableTransaction = bcs::from_bytes(ptb)?;
+
+    if ptb.commands.is_empty() {
+        return Err(ApproveTransactionError::NoSealApproveCall);
+    }
+
+    let mut calls: Vec<SealApproveCall> = Vec::with_capacity(ptb.commands.len());
+    for (index, command) in ptb.commands.iter().enumerate() {
+        let Command::MoveCall(call) = command else {
+            return Err(ApproveTransactionError::NotMoveCall { index });
+        };
+
+        let function = call.function.as_str();
+        if !function.starts_with(SEAL_APPROVE_PREFIX) {
+            return Err(ApproveTransactionError::NotSealApprove {
+                index,
+                function: function.to_string(),
+            });
+        }
+
+        let package_id = ObjectID::from(call.package);
+        if let Some(first) = calls.first().filter(|first| first.package_id != package_id) {
+            return Err(ApproveTransactionError::MultiplePackages {
+                first: first.package_id,
+                other: package_id,
+            });
+        }
+
+        let value = match call.arguments.first() {
+            Some(Argument::Input(input)) => match ptb.inputs.get(*input as usize) {
+                Some(Input::Pure { value }) => value,
+                _ => return Err(ApproveTransactionError::MissingId { index }),
+            },
+            _ => return Err(ApproveTransactionError::MissingId { index }),
+        };
+        let id: Vec<u8> =
+            bcs::from_bytes(value).map_err(|_| ApproveTransactionError::MissingId { index })?;
+
+        calls.push(SealApproveCall {
+            package_id,
+            module: call.module.as_str().to_string(),
+            function: function.to_string(),
+            id,
+        });
+    }
+
+    Ok(calls)
+}
+
+/// Check that an approval PTB only calls `seal_approve*` functions of a single package.
+///
+/// Upgrades of `package_id` have other ids, which cannot be told apart from unrelated packages
+/// without their on-chain lineage, so any package is accepted when `package_versions` is
+/// `None`; key servers then check the lineage themselves. With `Some(versions)`, the called
+/// package must be `package_id` or one of `versions`.
+pub fn check_approve_transaction(
+    ptb: &[u8],
+    package_id: &ObjectID,
+    package_versions: Option<&[ObjectID]>,
+) -> Result<Vec<SealApproveCall>, ApproveTransactionError> {
+    let calls = seal_approve_calls(ptb)?;
+
+    let called_package_id = calls[0].package_id;
+    let allowed = called_package_id == *package_id
+        || package_versions.is_none_or(|versions| versions.contains(&called_package_id));
+    if !allowed {
+        return Err(ApproveTransactionError::PackageMismatch {
+            package_id: called_package_id,
+            session_package_id: *package_id,
+        });
+    }
+
+    Ok(calls)
+}
@@ -12,12 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::approve_transaction::check_approve_transaction;
 use crate::cache::SealCache;
 use crate::cache_key::{DerivedKeyCacheKey, KeyServerInfoCacheKey};
-use crate::crypto::{EncryptedObject, FetchKeyRequest, FetchKeyResponse, seal_decrypt_all_objects};
+use crate::crypto::{
+    EncryptedObject, FetchKeyRequest, FetchKeyResponse, ServiceResponse, seal_decrypt_all_objects,
+    seal_encrypt_with_rng, verify_proof_of_possession,
+};
 use crate::error::SealClientError;
 use crate::generic_types::{BCSSerializableProgrammableTransaction, ObjectID};
-use crate::http_client::HttpClient;
+use crate::http_client::{GET_NOT_IMPLEMENTED, HttpClient, HttpResponse};
+use crate::retry::RetryPolicy;
+use crate::rng::{SealRng, SharedRng, shared_rng};
 use crate::session_key::SessionKey;
 use crate::sui_client::SuiClient;
 use fastcrypto::groups::FromTrustedByteArray;
@@ -27,8 +33,11 @@ use seal_crypto::{EncryptionInput, IBEPublicKeys, seal_encrypt};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::Infallible;
 use std::fmt::Display;
-use std::sync::Arc;
+use std::future::Future;
+use std::sync::{Arc, PoisonError};
+use std::time::Duration;
 
 /// PartialKeyServer struct for a committee member.
 ///
@@ -66,6 +75,8 @@ pub enum ServerType {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeyServerInfo {
     pub object_id: ObjectID,
+    /// Package defining the object's `key_server::KeyServer` type.
+    pub package_id: ObjectID,
     pub name: String,
     pub public_key: String,
     pub server_type: ServerType,
@@ -87,6 +98,14 @@ impl KeyServerConfig {
             aggregator_url,
         }
     }
+
+    pub fn object_id(&self) -> ObjectID {
+        self.object_id
+    }
+
+    pub fn aggregator_url(&self) -> Option<&str> {
+        self.aggregator_url.as_deref()
+    }
 }
 
 pub type DerivedKeys = (ObjectID, FetchKeyResponse);
@@ -106,6 +125,13 @@ where
     derived_key_cache: DerivedKeysCache,
     sui_client: Sui,
     http_client: Http,
+    trusted_seal_packages: Option<Vec<ObjectID>>,
+    package_versions: HashMap<ObjectID, Vec<ObjectID>>,
+    verify_key_servers: bool,
+    key_servers: Vec<KeyServerConfig>,
+    retry_policy: RetryPolicy,
+    request_timeout: Option<Duration>,
+    rng: Option<SharedRng>,
 }
 
 impl<KeyServerInfoCache, DerivedKeysCache, SuiError, Sui, HttpError, Http>
@@ -130,9 +156,131 @@ where
             derived_key_cache,
             sui_client,
             http_client,
+            trusted_seal_packages: None,
+            package_versions: HashMap::new(),
+            verify_key_servers: false,
+            key_servers: vec![],
+            retry_policy: RetryPolicy::default(),
+            request_timeout: None,
+            rng: None,
         }
     }
 
+    /// Only accept key servers whose `key_server::KeyServer` type is defined by one of
+    /// `package_ids`.
+    ///
+    /// Move types keep the id of the package that first defined them, so list the original
+    /// package id of each trusted Seal deployment, not the id of its latest upgrade. Without
+    /// this call, any `key_server::KeyServer` object is accepted.
+    pub fn with_trusted_seal_packages<ID>(
+        mut self,
+        package_ids: impl IntoIterator<Item = ID>,
+    ) -> Self
+    where
+        ObjectID: From<ID>,
+    {
+        self.trusted_seal_packages = Some(package_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn trusted_seal_packages(&self) -> Option<&[ObjectID]> {
+        self.trusted_seal_packages.as_deref()
+    }
+
+    /// Accept approval PTBs calling `upgraded_package_ids` for objects encrypted under
+    /// `package_id`.
+    ///
+    /// Objects and session keys always use the original package id, while key servers accept
+    /// `seal_approve*` calls into any upgrade of it. Without this call, approval PTBs may call
+    /// any package and the key servers check the upgrade lineage. Once versions are registered,
+    /// decryption rejects approval PTBs that call neither `package_id` nor one of them before
+    /// contacting any key server.
+    pub fn with_package_versions<ID>(
+        mut self,
+        package_id: ID,
+        upgraded_package_ids: impl IntoIterator<Item = ID>,
+    ) -> Self
+    where
+        ObjectID: From<ID>,
+    {
+        self.package_versions
+            .entry(package_id.into())
+            .or_default()
+            .extend(upgraded_package_ids.into_iter().map(Into::into));
+        self
+    }
+
+    /// Run [`verify_key_servers`](Self::verify_key_servers) on every key server the first time
+    /// it is resolved, before it is cached and used for encryption or decryption. Servers from
+    /// packages outside [`with_trusted_seal_packages`](Self::with_trusted_seal_packages) are
+    /// rejected first and never contacted.
+    ///
+    /// Disabled by default, as it costs one extra request per key server.
+    pub fn with_key_server_verification(mut self, enabled: bool) -> Self {
+        self.verify_key_servers = enabled;
+        self
+    }
+
+    pub fn key_server_verification(&self) -> bool {
+        self.verify_key_servers
+    }
+
+    /// Register well-known key servers.
+    ///
+    /// Their aggregator URLs are used when decrypting, unless the call provides its own URL for
+    /// the same server.
+    pub fn with_key_servers(
+        mut self,
+        key_servers: impl IntoIterator<Item = KeyServerConfig>,
+    ) -> Self {
+        self.key_servers = key_servers.into_iter().collect();
+        self
+    }
+
+    pub fn key_servers(&self) -> &[KeyServerConfig] {
+        &self.key_servers
+    }
+
+    /// Retry failed key server requests according to `retry_policy`.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+
+    /// Abort each key server request attempt after `timeout`.
+    ///
+    /// This bounds a single attempt: with retries enabled, a call can take up to
+    /// `max_attempts` times as long, plus the backoff delays.
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.request_timeout
+    }
+
+    /// Draw the ephemeral ElGamal keys of key server requests from `rng` instead of
+    /// `thread_rng()`.
+    ///
+    /// With a seeded RNG and the same session key, decryptions send the same requests, which
+    /// makes recorded key server responses replayable. Encryptions draw their key shares and
+    /// IBE randomness from `rng` too, so the same seed gives byte-identical encrypted objects.
+    /// Session keys take their own RNG, see [`SessionKeyBuilder::rng`](crate::session_key::SessionKeyBuilder::rng).
+    pub fn with_rng<R: SealRng + 'static>(self, rng: R) -> Self {
+        self.with_shared_rng(shared_rng(rng))
+    }
+
+    /// Same as [`with_rng`](Self::with_rng), with an RNG that can be shared between clients.
+    pub fn with_shared_rng(mut self, rng: SharedRng) -> Self {
+        self.rng = Some(rng);
+        self
+    }
+
     /// Retrieves [`KeyServerInfo`] for a single key server, using the cache when available.
     ///
     /// This is useful when you want to inspect a key server's metadata (name, URL, public key)
@@ -147,10 +295,26 @@ where
         let object_id: ObjectID = key_server_id.into();
         let cache_key = KeyServerInfoCacheKey::new(object_id);
 
-        self.key_server_info_cache
-            .try_get_with(cache_key, self.sui_client.get_key_server_info(object_id.0))
+        let info = self
+            .key_server_info_cache
+            .try_get_with(cache_key, async {
+                let info = self.sui_client.get_key_server_info(object_id.0).await?;
+
+                // Reject untrusted packages before any request reaches the server's URL.
+                self.ensure_trusted_package(&info)?;
+                if self.verify_key_servers {
+                    self.verify_key_servers(std::slice::from_ref(&info)).await?;
+                }
+
+                Ok::<_, SealClientError>(info)
+            })
             .await
-            .map_err(unwrap_cache_error)
+            .map_err(unwrap_cache_error)?;
+
+        // The cache may be shared with a client trusting other packages.
+        self.ensure_trusted_package(&info)?;
+
+        Ok(info)
     }
 
     /// Fetch committee details for a committee-type key server.
@@ -186,7 +350,7 @@ where
     /// ```rust,no_run
     /// # use seal_sdk_rs::error::SealClientError;
     /// # use seal_sdk_rs::generic_types::ObjectID;
-    /// # use seal_sdk_rs::native_sui_sdk::client::seal_client::SealClient;
+    /// # use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
     /// #
     /// # #[derive(Clone)]
     /// # struct DemoSetup {
@@ -194,7 +358,7 @@ where
     /// #     key_server_id: ObjectID,
     /// # }
     /// #
-    /// # async fn demo(client: &SealClient, setup: &DemoSetup) -> Result<(), SealClientError> {
+    /// # async fn demo(client: &JsonRpcSealClient, setup: &DemoSetup) -> Result<(), SealClientError> {
     /// let (encrypted, recovery_key) = client
     ///     .encrypt(
     ///         setup.approve_package_id,
@@ -236,7 +400,7 @@ where
     /// ```rust,no_run
     /// # use seal_sdk_rs::error::SealClientError;
     /// # use seal_sdk_rs::generic_types::ObjectID;
-    /// # use seal_sdk_rs::native_sui_sdk::client::seal_client::SealClient;
+    /// # use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
     /// #
     /// # #[derive(Clone)]
     /// # struct DemoSetup {
@@ -244,7 +408,7 @@ where
     /// #     key_server_id: ObjectID,
     /// # }
     /// #
-    /// # async fn demo(client: &SealClient, setup: &DemoSetup) -> Result<(), SealClientError> {
+    /// # async fn demo(client: &JsonRpcSealClient, setup: &DemoSetup) -> Result<(), SealClientError> {
     /// let encrypted = client
     ///     .encrypt_multiple(
     ///         setup.approve_package_id,
@@ -290,7 +454,7 @@ where
     /// ```rust,no_run
     /// # use seal_sdk_rs::error::SealClientError;
     /// # use seal_sdk_rs::generic_types::ObjectID;
-    /// # use seal_sdk_rs::native_sui_sdk::client::seal_client::SealClient;
+    /// # use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
     /// #
     /// # #[derive(Clone)]
     /// # struct DemoSetup {
@@ -298,7 +462,7 @@ where
     /// #     key_server_id: ObjectID,
     /// # }
     /// #
-    /// # async fn demo(client: &SealClient, setup: &DemoSetup) -> Result<(), SealClientError> {
+    /// # async fn demo(client: &JsonRpcSealClient, setup: &DemoSetup) -> Result<(), SealClientError> {
     /// let data = vec![0u8, 1, 2, 3];
     /// let (encrypted, recovery_key) = client
     ///     .encrypt_bytes(
@@ -347,7 +511,7 @@ where
     /// ```rust,no_run
     /// # use seal_sdk_rs::error::SealClientError;
     /// # use seal_sdk_rs::generic_types::ObjectID;
-    /// # use seal_sdk_rs::native_sui_sdk::client::seal_client::SealClient;
+    /// # use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
     /// #
     /// # #[derive(Clone)]
     /// # struct DemoSetup {
@@ -355,7 +519,7 @@ where
     /// #     key_server_id: ObjectID,
     /// # }
     /// #
-    /// # async fn demo(client: &SealClient, setup: &DemoSetup) -> Result<(), SealClientError> {
+    /// # async fn demo(client: &JsonRpcSealClient, setup: &DemoSetup) -> Result<(), SealClientError> {
     /// let payloads = vec![vec![0u8, 1, 2, 3], vec![4u8, 5, 6, 7, 8]];
     /// let encrypted = client
     ///     .encrypt_multiple_bytes(
@@ -394,27 +558,66 @@ where
 
         let public_keys = IBEPublicKeys::BonehFranklinBLS12381(public_keys_g2);
 
-        let mut results = Vec::with_capacity(data.len());
-
-        for data in data {
-            let (encrypted_object, recovery_key) = seal_encrypt(
-                package_id.0.into(),
-                id.clone(),
-                key_servers
-                    .iter()
-                    .map(|e| e.object_id.into())
-                    .collect::<Vec<_>>(),
-                &public_keys,
-                threshold,
-                EncryptionInput::Aes256Gcm { data, aad: None },
-            )?;
-
-            results.push((encrypted_object.into(), recovery_key));
-        }
+        let key_server_ids: Vec<ObjectID> = key_servers.iter().map(|e| e.object_id).collect();
+
+        // Encryption is synchronous: the RNG lock is taken and released in this block, so the
+        // guard never lives across an `.await`.
+        let results = {
+            let mut rng = self
+                .rng
+                .as_ref()
+                .map(|rng| rng.lock().unwrap_or_else(PoisonError::into_inner));
+
+            data.into_iter()
+                .map(|data| {
+                    let input = EncryptionInput::Aes256Gcm { data, aad: None };
+                    let encrypted = match rng.as_mut() {
+                        Some(rng) => seal_encrypt_with_rng(
+                            package_id,
+                            id.clone(),
+                            key_server_ids.clone(),
+                            &public_keys,
+                            threshold,
+                            input,
+                            &mut &mut **rng,
+                        )?,
+                        None => {
+                            let (encrypted_object, recovery_key) = seal_encrypt(
+                                package_id.0.into(),
+                                id.clone(),
+                                key_server_ids.iter().map(|e| (*e).into()).collect(),
+                                &public_keys,
+                                threshold,
+                                input,
+                            )?;
+                            (encrypted_object.into(), recovery_key)
+                        }
+                    };
+
+                    Ok::<_, SealClientError>(encrypted)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
 
         Ok(results)
     }
 
+    /// Check that each independent key server still holds the key registered on-chain.
+    ///
+    /// Calls `{url}/v1/service` and verifies the returned proof of possession against the
+    /// on-chain public key, which catches servers whose URL now points at a different key.
+    /// Committee servers are skipped: their on-chain URL is empty and aggregators do not hold
+    /// the committee key.
+    pub async fn verify_key_servers(
+        &self,
+        key_servers: &[KeyServerInfo],
+    ) -> Result<(), SealClientError> {
+        join_all(key_servers.iter().map(|info| self.verify_key_server(info)))
+            .await
+            .into_iter()
+            .collect()
+    }
+
     #[allow(dead_code)]
     pub async fn key_server_info(
         &self,
@@ -435,7 +638,7 @@ where
     /// # use seal_sdk_rs::generic_types::BCSSerializableProgrammableTransaction;
     /// # use seal_sdk_rs::crypto::EncryptedObject;
     /// # use seal_sdk_rs::error::SealClientError;
-    /// # use seal_sdk_rs::native_sui_sdk::client::seal_client::SealClient;
+    /// # use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
     /// # use seal_sdk_rs::session_key::SessionKey;
     /// # struct DemoTransaction;
     /// # impl BCSSerializableProgrammableTransaction for DemoTransaction {
@@ -444,7 +647,7 @@ where
     /// #     }
     /// # }
     /// # async fn demo(
-    /// #     client: &SealClient,
+    /// #     client: &JsonRpcSealClient,
     /// #     session_key: &SessionKey,
     /// #     encrypted: &EncryptedObject,
     /// # ) -> Result<(), SealClientError> {
@@ -494,7 +697,7 @@ where
     /// # use seal_sdk_rs::generic_types::BCSSerializableProgrammableTransaction;
     /// # use seal_sdk_rs::crypto::EncryptedObject;
     /// # use seal_sdk_rs::error::SealClientError;
-    /// # use seal_sdk_rs::native_sui_sdk::client::seal_client::SealClient;
+    /// # use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
     /// # use seal_sdk_rs::session_key::SessionKey;
     ///
     /// # struct DemoTransaction;
@@ -505,7 +708,7 @@ where
     /// #     }
     /// # }
     /// # async fn demo(
-    /// #     client: &SealClient,
+    /// #     client: &JsonRpcSealClient,
     /// #     session_key: &SessionKey,
     /// #     encrypted: &[EncryptedObject],
     /// # ) -> Result<(), SealClientError> {
@@ -564,7 +767,7 @@ where
     /// # use seal_sdk_rs::generic_types::BCSSerializableProgrammableTransaction;
     /// # use seal_sdk_rs::crypto::EncryptedObject;
     /// # use seal_sdk_rs::error::SealClientError;
-    /// # use seal_sdk_rs::native_sui_sdk::client::seal_client::SealClient;
+    /// # use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
     /// # use seal_sdk_rs::session_key::SessionKey;
     /// # struct DemoTransaction;
     /// # impl BCSSerializableProgrammableTransaction for DemoTransaction {
@@ -573,7 +776,7 @@ where
     /// #     }
     /// # }
     /// # async fn demo(
-    /// #     client: &SealClient,
+    /// #     client: &JsonRpcSealClient,
     /// #     session_key: &SessionKey,
     /// #     encrypted: &EncryptedObject,
     /// # ) -> Result<(), SealClientError> {
@@ -617,13 +820,19 @@ where
     /// otherwise the approval transaction will fail. The byte slices should be obtained
     /// by serializing [`EncryptedObject`] instances with `bcs::to_bytes`.
     ///
+    /// Each object is checked with [`EncryptedObject::validate`] before any key server is
+    /// contacted, so malformed input fails with [`SealClientError::EncryptedObject`]. Objects
+    /// must belong to the session key's package, and the approval PTB may only call
+    /// `seal_approve*` functions of a single package (see
+    /// [`with_package_versions`](Self::with_package_versions) to restrict it to known versions).
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
     /// # use seal_sdk_rs::generic_types::BCSSerializableProgrammableTransaction;
     /// # use seal_sdk_rs::crypto::EncryptedObject;
     /// # use seal_sdk_rs::error::SealClientError;
-    /// # use seal_sdk_rs::native_sui_sdk::client::seal_client::SealClient;
+    /// # use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
     /// # use seal_sdk_rs::session_key::SessionKey;
     /// # struct DemoTransaction;
     /// # impl BCSSerializableProgrammableTransaction for DemoTransaction {
@@ -632,7 +841,7 @@ where
     /// #     }
     /// # }
     /// # async fn demo(
-    /// #     client: &SealClient,
+    /// #     client: &JsonRpcSealClient,
     /// #     session_key: &SessionKey,
     /// #     encrypted: &[EncryptedObject],
     /// # ) -> Result<(), SealClientError> {
@@ -666,11 +875,34 @@ where
             return Ok(vec![]);
         }
 
+        let aggregator_urls_for_ker_server =
+            self.with_registered_aggregator_urls(aggregator_urls_for_ker_server);
+
         let encrypted_objects = encrypted_objects_data
             .iter()
             .map(|bytes| bcs::from_bytes::<EncryptedObject>(bytes))
             .collect::<Result<Vec<_>, _>>()?;
 
+        for encrypted_object in encrypted_objects.iter() {
+            encrypted_object.validate()?;
+
+            if encrypted_object.package_id != *session_key.package_id() {
+                return Err(SealClientError::PackageIdMismatch {
+                    object_package_id: encrypted_object.package_id,
+                    session_package_id: *session_key.package_id(),
+                });
+            }
+        }
+
+        let approve_transaction_data = approve_transaction_data.to_bcs_bytes()?;
+        check_approve_transaction(
+            &approve_transaction_data,
+            session_key.package_id(),
+            self.package_versions
+                .get(session_key.package_id())
+                .map(Vec::as_slice),
+        )?;
+
         let first_encrypted_object = encrypted_objects.first().unwrap();
 
         let services: Vec<KeyServerConfig> = first_encrypted_object
@@ -690,8 +922,13 @@ where
             .into_iter()
             .collect::<HashMap<_, _>>();
 
-        let (signed_request, enc_secret) =
-            session_key.get_fetch_key_request(approve_transaction_data.to_bcs_bytes()?)?;
+        let (signed_request, enc_secret) = match &self.rng {
+            Some(rng) => {
+                let mut rng = rng.lock().unwrap_or_else(PoisonError::into_inner);
+                session_key.get_fetch_key_request_with_rng(approve_transaction_data, &mut *rng)?
+            }
+            None => session_key.get_fetch_key_request(approve_transaction_data)?,
+        };
 
         let derived_keys = self
             .fetch_derived_keys(
@@ -718,43 +955,208 @@ where
         &self,
         key_servers: Vec<KeyServerConfig>,
     ) -> Result<Vec<KeyServerInfo>, SealClientError> {
-        let mut key_server_info_futures = vec![];
-        for key_server in key_servers {
-            let cache_key = KeyServerInfoCacheKey::new(key_server.object_id);
-
-            let future = async move {
-                let result = self
-                    .key_server_info_cache
-                    .try_get_with(
-                        cache_key,
-                        self.sui_client.get_key_server_info(key_server.object_id.0),
-                    )
-                    .await
-                    .map_err(unwrap_cache_error);
-
-                match &result {
-                    Ok(info) => log::debug!(
-                        "seal: resolved key server object_id={} type={:?}",
-                        info.object_id,
-                        info.server_type,
-                    ),
-                    Err(err) => log::debug!(
-                        "seal: failed to resolve key server object_id={}: {}",
-                        key_server.object_id,
-                        err,
-                    ),
+        // Cached entries are collected first, the misses are then resolved with a single batched
+        // lookup and written back to the cache.
+        let cached = join_all(key_servers.iter().map(|key_server| {
+            self.key_server_info_cache
+                .get(KeyServerInfoCacheKey::new(key_server.object_id))
+        }))
+        .await;
+
+        let missing_ids = key_servers
+            .iter()
+            .zip(&cached)
+            .filter(|(_, cached)| cached.is_none())
+            .map(|(key_server, _)| key_server.object_id.0)
+            .collect::<Vec<_>>();
+
+        let mut resolved = if missing_ids.is_empty() {
+            vec![]
+        } else {
+            log::debug!(
+                "seal: resolving {} key servers ({} cached)",
+                missing_ids.len(),
+                key_servers.len() - missing_ids.len(),
+            );
+
+            let resolved = self.sui_client.get_key_servers_info(missing_ids).await?;
+
+            // Reject untrusted packages before any request reaches the servers' URLs.
+            for info in &resolved {
+                self.ensure_trusted_package(info)?;
+            }
+            if self.verify_key_servers {
+                self.verify_key_servers(&resolved).await?;
+            }
+
+            resolved
+        }
+        .into_iter();
+
+        let mut key_servers_info = Vec::with_capacity(key_servers.len());
+        for (key_server, cached) in key_servers.iter().zip(cached) {
+            let info = match cached {
+                // The cache may be shared with a client trusting other packages.
+                Some(info) => {
+                    self.ensure_trusted_package(&info)?;
+                    info
+                }
+                None => {
+                    let info =
+                        resolved
+                            .next()
+                            .ok_or_else(|| SealClientError::MissingKeyServerInfo {
+                                object_id: key_server.object_id,
+                            })?;
+
+                    self.key_server_info_cache
+                        .try_get_with(KeyServerInfoCacheKey::new(key_server.object_id), async {
+                            Ok::<_, Infallible>(info)
+                        })
+                        .await
+                        .map_err(|_| SealClientError::MissingKeyServerInfo {
+                            object_id: key_server.object_id,
+                        })?
                 }
+            };
+
+            log::debug!(
+                "seal: resolved key server object_id={} type={:?}",
+                info.object_id,
+                info.server_type,
+            );
+
+            key_servers_info.push(info);
+        }
+
+        Ok(key_servers_info)
+    }
 
-                result
+    /// Complete `aggregator_urls` with the aggregator URLs of the registered key servers.
+    fn with_registered_aggregator_urls(
+        &self,
+        mut aggregator_urls: HashMap<ObjectID, String>,
+    ) -> HashMap<ObjectID, String> {
+        for key_server in &self.key_servers {
+            if let Some(url) = &key_server.aggregator_url {
+                aggregator_urls
+                    .entry(key_server.object_id)
+                    .or_insert_with(|| url.clone());
+            }
+        }
+
+        aggregator_urls
+    }
+
+    /// Send a key server request, applying the request timeout and the retry policy.
+    async fn send_with_retries<Request, Fut>(
+        &self,
+        url: &str,
+        request: Request,
+    ) -> Result<HttpResponse, SealClientError>
+    where
+        Request: Fn() -> Fut,
+        Fut: Future<Output = Result<HttpResponse, HttpError>>,
+    {
+        let max_attempts = self.retry_policy.max_attempts.max(1);
+        let mut attempt = 1;
+
+        loop {
+            let result = match self.request_timeout {
+                Some(timeout) => match tokio::time::timeout(timeout, request()).await {
+                    Ok(result) => result.map_err(SealClientError::from),
+                    Err(_) => Err(SealClientError::KeyServerTimeout {
+                        url: url.to_string(),
+                        timeout,
+                    }),
+                },
+                None => request().await.map_err(SealClientError::from),
+            };
+
+            let delay = match &result {
+                Ok(response) if RetryPolicy::is_retryable_status(response.status) => response
+                    .header("retry-after")
+                    .and_then(|retry_after| self.retry_policy.retry_after(retry_after))
+                    .unwrap_or_else(|| self.retry_policy.backoff(attempt)),
+                Ok(_) => return result,
+                Err(_) => self.retry_policy.backoff(attempt),
             };
 
-            key_server_info_futures.push(future);
+            if attempt >= max_attempts {
+                return result;
+            }
+
+            log::debug!(
+                "seal: retrying url={} in {:?} (attempt {}/{})",
+                url,
+                delay,
+                attempt + 1,
+                max_attempts,
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
         }
+    }
 
-        join_all(key_server_info_futures)
-            .await
-            .into_iter()
-            .collect::<Result<_, _>>()
+    fn ensure_trusted_package(&self, info: &KeyServerInfo) -> Result<(), SealClientError> {
+        match &self.trusted_seal_packages {
+            Some(trusted) if !trusted.contains(&info.package_id) => {
+                Err(SealClientError::UntrustedKeyServerPackage {
+                    object_id: info.object_id,
+                    package_id: info.package_id,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn verify_key_server(&self, info: &KeyServerInfo) -> Result<(), SealClientError> {
+        let ServerType::Independent { url: base_url } = &info.server_type else {
+            return Ok(());
+        };
+
+        let mut headers = HashMap::new();
+
+        headers.insert("Client-Sdk-Version".to_string(), "1.0.0".to_string());
+        headers.insert("Client-Sdk-Type".to_string(), "typescript".to_string());
+
+        let url = format!("{}/v1/service?service_id={}", base_url, info.object_id);
+        log::debug!(
+            "seal: verifying key server object_id={} url={}",
+            info.object_id,
+            url,
+        );
+        let response = self
+            .send_with_retries(&url, || {
+                self.http_client.get_with_headers(&url, headers.clone())
+            })
+            .await?;
+
+        if response.status == 405 && response.text == GET_NOT_IMPLEMENTED {
+            return Err(SealClientError::HttpGetNotImplemented { url });
+        }
+
+        if !response.is_success() {
+            return Err(SealClientError::ErrorWhileVerifyingKeyServer {
+                url,
+                status: response.status,
+                response: response.text,
+            });
+        }
+
+        let service: ServiceResponse = serde_json::from_str(&response.text)?;
+        let public_key = self.decode_public_key(info)?;
+
+        if service.service_id != info.object_id
+            || verify_proof_of_possession(&service.pop, &public_key, &info.object_id.0).is_err()
+        {
+            return Err(SealClientError::KeyServerVerificationFailed {
+                object_id: info.object_id,
+                url,
+            });
+        }
+
+        Ok(())
     }
 
     async fn fetch_derived_keys(
@@ -801,16 +1203,22 @@ where
                     base_url,
                 );
                 let response = self
-                    .http_client
-                    .post(&url, headers, request_json_clone)
+                    .send_with_retries(&url, || {
+                        self.http_client.post_with_headers(
+                            &url,
+                            headers.clone(),
+                            request_json_clone.clone(),
+                        )
+                    })
                     .await?;
 
                 if !response.is_success() {
                     log::debug!(
-                        "seal: key server error object_id={} url={} status={} body={}",
+                        "seal: key server error object_id={} url={} status={} version={:?} body={}",
                         server.object_id,
                         url,
                         response.status,
+                        response.header("x-keyserver-version"),
                         response.text.chars().take(500).collect::<String>(),
                     );
                     return Err(SealClientError::ErrorWhileFetchingDerivedKeys {
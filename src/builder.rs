@@ -0,0 +1,378 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fluent construction of [`BaseSealClient`].
+//!
+//! [`SealClientBuilder`] starts from the same defaults as the ready-made specializations (no
+//! caching, `reqwest::Client` when the `reqwest` feature is enabled, no retries) and only
+//! requires a Sui client:
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "client")]
+//! # fn demo() -> Result<(), Box<dyn std::error::Error>> {
+//! use seal_sdk_rs::builder::SealClientBuilder;
+//! use seal_sdk_rs::json_rpc::sui_client::JsonRpcSuiClient;
+//! use seal_sdk_rs::retry::RetryPolicy;
+//! use std::time::Duration;
+//!
+//! let http_client = reqwest::Client::new();
+//! let seal_client = SealClientBuilder::new()
+//!     .sui_client(JsonRpcSuiClient::new(
+//!         http_client.clone(),
+//!         "https://fullnode.testnet.sui.io:443",
+//!     ))
+//!     .http_client(http_client)
+//!     .retry_policy(RetryPolicy::new(3))
+//!     .request_timeout(Duration::from_secs(10))
+//!     .build()?;
+//! # let _ = seal_client;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::base_client::{BaseSealClient, DerivedKeys, KeyServerConfig, KeyServerInfo};
+use crate::cache::{NoCache, SealCache};
+use crate::cache_key::{DerivedKeyCacheKey, KeyServerInfoCacheKey};
+use crate::error::SealClientError;
+use crate::generic_types::ObjectID;
+use crate::http_client::HttpClient;
+use crate::retry::RetryPolicy;
+use crate::rng::{SealRng, SharedRng, shared_rng};
+use crate::sui_client::SuiClient;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SealClientBuilderError {
+    #[error("Key server {object_id} is registered more than once")]
+    DuplicateKeyServer { object_id: ObjectID },
+
+    #[error("Invalid aggregator URL for key server {object_id}: {url}")]
+    InvalidAggregatorUrl { object_id: ObjectID, url: String },
+
+    #[error("The retry policy must allow at least one attempt")]
+    ZeroRetryAttempts,
+
+    #[error("The initial retry backoff {initial_backoff:?} exceeds the maximum {max_backoff:?}")]
+    InvalidRetryBackoff {
+        initial_backoff: Duration,
+        max_backoff: Duration,
+    },
+
+    #[error("The request timeout must be greater than zero")]
+    ZeroRequestTimeout,
+
+    #[error("The trusted Seal package list is empty, so no key server would be accepted")]
+    NoTrustedSealPackages,
+}
+
+/// Placeholder for a component that has not been provided to [`SealClientBuilder`] yet.
+///
+/// `build()` is only available once every placeholder has been replaced, so a missing Sui
+/// client is reported at compile time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Unset;
+
+#[cfg(feature = "reqwest")]
+type DefaultHttpClient = reqwest::Client;
+#[cfg(not(feature = "reqwest"))]
+type DefaultHttpClient = Unset;
+
+/// Builder for [`BaseSealClient`]. See the [module documentation](self) for an example.
+pub struct SealClientBuilder<KeyServerInfoCache, DerivedKeysCache, Sui, Http> {
+    key_server_info_cache: KeyServerInfoCache,
+    derived_key_cache: DerivedKeysCache,
+    sui_client: Sui,
+    http_client: Http,
+    key_servers: Vec<KeyServerConfig>,
+    trusted_seal_packages: Option<Vec<ObjectID>>,
+    package_versions: HashMap<ObjectID, Vec<ObjectID>>,
+    verify_key_servers: bool,
+    retry_policy: RetryPolicy,
+    request_timeout: Option<Duration>,
+    rng: Option<SharedRng>,
+}
+
+impl
+    SealClientBuilder<
+        NoCache<KeyServerInfoCacheKey, KeyServerInfo>,
+        NoCache<DerivedKeyCacheKey, DerivedKeys>,
+        Unset,
+        DefaultHttpClient,
+    >
+{
+    pub fn new() -> Self {
+        SealClientBuilder {
+            key_server_info_cache: NoCache::default(),
+            derived_key_cache: NoCache::default(),
+            sui_client: Unset,
+            http_client: DefaultHttpClient::default(),
+            key_servers: vec![],
+            trusted_seal_packages: None,
+            package_versions: HashMap::new(),
+            verify_key_servers: false,
+            retry_policy: RetryPolicy::default(),
+            request_timeout: None,
+            rng: None,
+        }
+    }
+}
+
+impl Default
+    for SealClientBuilder<
+        NoCache<KeyServerInfoCacheKey, KeyServerInfo>,
+        NoCache<DerivedKeyCacheKey, DerivedKeys>,
+        Unset,
+        DefaultHttpClient,
+    >
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<KeyServerInfoCache, DerivedKeysCache, Sui, Http>
+    SealClientBuilder<KeyServerInfoCache, DerivedKeysCache, Sui, Http>
+{
+    pub fn sui_client<NewSui>(
+        self,
+        sui_client: NewSui,
+    ) -> SealClientBuilder<KeyServerInfoCache, DerivedKeysCache, NewSui, Http> {
+        SealClientBuilder {
+            key_server_info_cache: self.key_server_info_cache,
+            derived_key_cache: self.derived_key_cache,
+            sui_client,
+            http_client: self.http_client,
+            key_servers: self.key_servers,
+            trusted_seal_packages: self.trusted_seal_packages,
+            package_versions: self.package_versions,
+            verify_key_servers: self.verify_key_servers,
+            retry_policy: self.retry_policy,
+            request_timeout: self.request_timeout,
+            rng: self.rng,
+        }
+    }
+
+    pub fn http_client<NewHttp>(
+        self,
+        http_client: NewHttp,
+    ) -> SealClientBuilder<KeyServerInfoCache, DerivedKeysCache, Sui, NewHttp> {
+        SealClientBuilder {
+            key_server_info_cache: self.key_server_info_cache,
+            derived_key_cache: self.derived_key_cache,
+            sui_client: self.sui_client,
+            http_client,
+            key_servers: self.key_servers,
+            trusted_seal_packages: self.trusted_seal_packages,
+            package_versions: self.package_versions,
+            verify_key_servers: self.verify_key_servers,
+            retry_policy: self.retry_policy,
+            request_timeout: self.request_timeout,
+            rng: self.rng,
+        }
+    }
+
+    pub fn key_server_info_cache<NewCache>(
+        self,
+        key_server_info_cache: NewCache,
+    ) -> SealClientBuilder<NewCache, DerivedKeysCache, Sui, Http> {
+        SealClientBuilder {
+            key_server_info_cache,
+            derived_key_cache: self.derived_key_cache,
+            sui_client: self.sui_client,
+            http_client: self.http_client,
+            key_servers: self.key_servers,
+            trusted_seal_packages: self.trusted_seal_packages,
+            package_versions: self.package_versions,
+            verify_key_servers: self.verify_key_servers,
+            retry_policy: self.retry_policy,
+            request_timeout: self.request_timeout,
+            rng: self.rng,
+        }
+    }
+
+    pub fn derived_key_cache<NewCache>(
+        self,
+        derived_key_cache: NewCache,
+    ) -> SealClientBuilder<KeyServerInfoCache, NewCache, Sui, Http> {
+        SealClientBuilder {
+            key_server_info_cache: self.key_server_info_cache,
+            derived_key_cache,
+            sui_client: self.sui_client,
+            http_client: self.http_client,
+            key_servers: self.key_servers,
+            trusted_seal_packages: self.trusted_seal_packages,
+            package_versions: self.package_versions,
+            verify_key_servers: self.verify_key_servers,
+            retry_policy: self.retry_policy,
+            request_timeout: self.request_timeout,
+            rng: self.rng,
+        }
+    }
+
+    /// Register a well-known key server. See [`BaseSealClient::with_key_servers`].
+    pub fn key_server(mut self, key_server: KeyServerConfig) -> Self {
+        self.key_servers.push(key_server);
+        self
+    }
+
+    /// Register several well-known key servers. See [`BaseSealClient::with_key_servers`].
+    pub fn key_servers(mut self, key_servers: impl IntoIterator<Item = KeyServerConfig>) -> Self {
+        self.key_servers.extend(key_servers);
+        self
+    }
+
+    /// See [`BaseSealClient::with_trusted_seal_packages`].
+    pub fn trusted_seal_packages<ID>(mut self, package_ids: impl IntoIterator<Item = ID>) -> Self
+    where
+        ObjectID: From<ID>,
+    {
+        self.trusted_seal_packages = Some(package_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// See [`BaseSealClient::with_package_versions`]. Calls for the same `package_id`
+    /// accumulate.
+    pub fn package_versions<ID>(
+        mut self,
+        package_id: ID,
+        upgraded_package_ids: impl IntoIterator<Item = ID>,
+    ) -> Self
+    where
+        ObjectID: From<ID>,
+    {
+        self.package_versions
+            .entry(package_id.into())
+            .or_default()
+            .extend(upgraded_package_ids.into_iter().map(Into::into));
+        self
+    }
+
+    /// See [`BaseSealClient::with_key_server_verification`].
+    pub fn verify_key_servers(mut self, enabled: bool) -> Self {
+        self.verify_key_servers = enabled;
+        self
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// See [`BaseSealClient::with_request_timeout`].
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+        self
+    }
+
+    /// See [`BaseSealClient::with_rng`].
+    pub fn rng<R: SealRng + 'static>(mut self, rng: R) -> Self {
+        self.rng = Some(shared_rng(rng));
+        self
+    }
+
+    fn validate(&self) -> Result<(), SealClientBuilderError> {
+        let mut registered = HashSet::new();
+        for key_server in &self.key_servers {
+            let object_id = key_server.object_id();
+            if !registered.insert(object_id) {
+                return Err(SealClientBuilderError::DuplicateKeyServer { object_id });
+            }
+
+            if let Some(url) = key_server
+                .aggregator_url()
+                .filter(|url| !(url.starts_with("https://") || url.starts_with("http://")))
+            {
+                return Err(SealClientBuilderError::InvalidAggregatorUrl {
+                    object_id,
+                    url: url.to_string(),
+                });
+            }
+        }
+
+        if self.retry_policy.max_attempts == 0 {
+            return Err(SealClientBuilderError::ZeroRetryAttempts);
+        }
+        if self.retry_policy.initial_backoff > self.retry_policy.max_backoff {
+            return Err(SealClientBuilderError::InvalidRetryBackoff {
+                initial_backoff: self.retry_policy.initial_backoff,
+                max_backoff: self.retry_policy.max_backoff,
+            });
+        }
+
+        if self.request_timeout == Some(Duration::ZERO) {
+            return Err(SealClientBuilderError::ZeroRequestTimeout);
+        }
+
+        if self
+            .trusted_seal_packages
+            .as_ref()
+            .is_some_and(|packages| packages.is_empty())
+        {
+            return Err(SealClientBuilderError::NoTrustedSealPackages);
+        }
+
+        Ok(())
+    }
+}
+
+impl<KeyServerInfoCache, DerivedKeysCache, SuiError, Sui, HttpError, Http>
+    SealClientBuilder<KeyServerInfoCache, DerivedKeysCache, Sui, Http>
+where
+    KeyServerInfoCache: SealCache<Key = KeyServerInfoCacheKey, Value = KeyServerInfo>,
+    DerivedKeysCache: SealCache<Key = DerivedKeyCacheKey, Value = DerivedKeys>,
+    SealClientError: From<SuiError>,
+    SuiError: Send + Sync + Display + 'static,
+    Sui: SuiClient<Error = SuiError>,
+    SealClientError: From<HttpError>,
+    Http: HttpClient<PostError = HttpError>,
+{
+    /// Validate the configuration and build the client.
+    #[allow(clippy::type_complexity)]
+    pub fn build(
+        self,
+    ) -> Result<
+        BaseSealClient<KeyServerInfoCache, DerivedKeysCache, SuiError, Sui, HttpError, Http>,
+        SealClientBuilderError,
+    > {
+        self.validate()?;
+
+        let mut client = BaseSealClient::new_custom(
+            self.key_server_info_cache,
+            self.derived_key_cache,
+            self.sui_client,
+            self.http_client,
+        )
+        .with_key_servers(self.key_servers)
+        .with_key_server_verification(self.verify_key_servers)
+        .with_retry_policy(self.retry_policy);
+
+        if let Some(package_ids) = self.trusted_seal_packages {
+            client = client.with_trusted_seal_packages(package_ids);
+        }
+        if let Some(timeout) = self.request_timeout {
+            client = client.with_request_timeout(timeout);
+        }
+        if let Some(rng) = self.rng {
+            client = client.with_shared_rng(rng);
+        }
+        for (package_id, upgraded_package_ids) in self.package_versions {
+            client = client.with_package_versions(package_id, upgraded_package_ids);
+        }
+
+        Ok(client)
+    }
+}
@@ -42,6 +42,15 @@ pub trait SealCache: Send + Sync {
     where
         Fut: Future<Output = Result<Self::Value, Error>> + Send,
         Error: Send + Sync + 'static;
+
+    /// Returns the cached value for `key`, if any, without initializing it.
+    ///
+    /// The default implementation always returns `None`. The client then resolves every key
+    /// server on each lookup, so caches that want to skip Sui calls for known entries should
+    /// override it.
+    async fn get(&self, _key: Self::Key) -> Option<Self::Value> {
+        None
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -84,6 +93,10 @@ impl<Key: Send + Sync, Value: Send + Sync> SealCache for NoCache<Key, Value> {
     {
         init.await.map_err(Arc::new)
     }
+
+    async fn get(&self, _key: Self::Key) -> Option<Self::Value> {
+        None
+    }
 }
 
 #[async_trait]
@@ -128,6 +141,10 @@ where
             }
         }
     }
+
+    async fn get(&self, key: Self::Key) -> Option<Self::Value> {
+        self.lock().await.get(&key).cloned()
+    }
 }
 
 #[cfg(feature = "moka")]
@@ -157,5 +174,9 @@ mod moka {
         {
             moka::future::Cache::try_get_with(self, key, init).await
         }
+
+        async fn get(&self, key: Self::Key) -> Option<Self::Value> {
+            moka::future::Cache::get(self, &key).await
+        }
     }
 }
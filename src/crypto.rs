@@ -4,20 +4,28 @@
 //! without patching the upstream crate, while staying faithful to the original
 //! implementation.
 
+use crate::error::EncryptedObjectError;
 use crate::generic_types::{ObjectID, SuiAddress};
+use base64::Engine;
 use fastcrypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
 use fastcrypto::encoding::{Encoding, Hex};
 use fastcrypto::error::{FastCryptoError, FastCryptoResult};
-use fastcrypto::groups::GroupElement;
-use fastcrypto::groups::bls12381::G2Element;
+use fastcrypto::groups::bls12381::{G1Element, G2Element};
+use fastcrypto::groups::{GroupElement, HashToGroupElement, Pairing};
+use fastcrypto::serde_helpers::ToFromByteArray;
+use fastcrypto::traits::AllowedRng;
+use seal_crypto::dem::{Aes256Gcm, Hmac256Ctr};
 use seal_crypto::elgamal::{PublicKey, SecretKey, VerificationKey};
 use seal_crypto::ibe::{UserSecretKey, verify_user_secret_key};
+use seal_crypto::tss::{self, SecretSharing};
+use seal_crypto::utils::generate_random_bytes;
 use seal_crypto::{
-    Ciphertext, IBEEncryptions, IBEPublicKeys, IBEUserSecretKeys, create_full_id, elgamal, ibe,
-    seal_decrypt,
+    Ciphertext, EncryptionInput, IBEEncryptions, IBEPublicKeys, IBEUserSecretKeys, KEY_SIZE,
+    KeyPurpose, create_full_id, derive_key, elgamal, ibe, seal_decrypt,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 use sui_sdk_types::UserSignature;
 
 pub type ElGamalPublicKey = PublicKey<UserSecretKey>;
@@ -25,6 +33,9 @@ pub type ElgamalEncryption = Encryption<UserSecretKey>;
 pub type ElgamalVerificationKey = VerificationKey<ibe::PublicKey>;
 pub type ElGamalSecretKey = SecretKey<fastcrypto::groups::bls12381::G1Element>;
 
+/// Only version of the [`EncryptedObject`] format produced and understood by Seal.
+pub const ENCRYPTED_OBJECT_VERSION: u8 = 0;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct EncryptedObject {
     pub version: u8,
@@ -36,6 +47,203 @@ pub struct EncryptedObject {
     pub ciphertext: Ciphertext,
 }
 
+/// Symmetric scheme encrypting the payload of an [`EncryptedObject`].
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DemType {
+    Aes256Gcm,
+    Hmac256Ctr,
+    /// No payload, only the derived key is shared.
+    Plain,
+}
+
+impl Display for DemType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            DemType::Aes256Gcm => "AES-256-GCM",
+            DemType::Hmac256Ctr => "HMAC-256-CTR",
+            DemType::Plain => "Plain",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Key server holding one share of an [`EncryptedObject`]'s key.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ServiceDescription {
+    pub object_id: ObjectID,
+    /// Index of the share, as used by the key server to derive its key.
+    pub share_index: u8,
+}
+
+/// Summary of an [`EncryptedObject`], see [`EncryptedObject::describe`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedObjectDescription {
+    pub version: u8,
+    pub package_id: ObjectID,
+    /// Hex-encoded identity, without the package prefix.
+    pub id: String,
+    /// Hex-encoded full identity the key servers derive keys for.
+    pub full_id: String,
+    pub threshold: u8,
+    pub services: Vec<ServiceDescription>,
+    pub dem_type: DemType,
+    pub ciphertext_len: usize,
+    pub aad_len: Option<usize>,
+}
+
+impl Display for EncryptedObjectDescription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Seal encrypted object (version {})", self.version)?;
+        writeln!(f, "  package id: {}", self.package_id)?;
+        writeln!(f, "  id:         0x{}", self.id)?;
+        writeln!(f, "  full id:    0x{}", self.full_id)?;
+        writeln!(
+            f,
+            "  threshold:  {} of {}",
+            self.threshold,
+            self.services.len()
+        )?;
+        for service in self.services.iter() {
+            writeln!(
+                f,
+                "  service:    {} (share {})",
+                service.object_id, service.share_index
+            )?;
+        }
+        write!(
+            f,
+            "  payload:    {}, {} bytes",
+            self.dem_type, self.ciphertext_len
+        )?;
+        if let Some(aad_len) = self.aad_len {
+            write!(f, ", {} bytes of AAD", aad_len)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl EncryptedObject {
+    /// Decode the BCS encoding produced by `encrypt_*` and the other Seal SDKs.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EncryptedObjectError> {
+        Ok(bcs::from_bytes(bytes)?)
+    }
+
+    /// Decode a hex-encoded object, with or without the `0x` prefix.
+    pub fn from_hex(value: &str) -> Result<Self, EncryptedObjectError> {
+        let value = value.trim();
+        let value = value.strip_prefix("0x").unwrap_or(value);
+
+        Self::from_bytes(&hex::decode(value)?)
+    }
+
+    /// Decode a base64-encoded object.
+    pub fn from_base64(value: &str) -> Result<Self, EncryptedObjectError> {
+        let bytes = base64::engine::general_purpose::STANDARD.decode(value.trim())?;
+
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncryptedObjectError> {
+        Ok(bcs::to_bytes(self)?)
+    }
+
+    /// Identity the key servers derive keys for, i.e. the package id followed by `id`.
+    pub fn full_id(&self) -> Vec<u8> {
+        create_full_id(&self.package_id.0, &self.id)
+    }
+
+    pub fn dem_type(&self) -> DemType {
+        match self.ciphertext {
+            Ciphertext::Aes256Gcm { .. } => DemType::Aes256Gcm,
+            Ciphertext::Hmac256Ctr { .. } => DemType::Hmac256Ctr,
+            Ciphertext::Plain => DemType::Plain,
+        }
+    }
+
+    /// Length of the encrypted payload, `0` for [`DemType::Plain`].
+    pub fn ciphertext_len(&self) -> usize {
+        match &self.ciphertext {
+            Ciphertext::Aes256Gcm { blob, .. } | Ciphertext::Hmac256Ctr { blob, .. } => blob.len(),
+            Ciphertext::Plain => 0,
+        }
+    }
+
+    /// Additional authenticated data bound to the payload, if any.
+    pub fn aad(&self) -> Option<&[u8]> {
+        match &self.ciphertext {
+            Ciphertext::Aes256Gcm { aad, .. } | Ciphertext::Hmac256Ctr { aad, .. } => {
+                aad.as_deref()
+            }
+            Ciphertext::Plain => None,
+        }
+    }
+
+    /// Check the object is well formed before contacting any key server: the version is
+    /// supported, `0 < threshold <= services`, there is one encrypted share per service and no
+    /// (key server, share index) pair appears twice.
+    pub fn validate(&self) -> Result<(), EncryptedObjectError> {
+        if self.version != ENCRYPTED_OBJECT_VERSION {
+            return Err(EncryptedObjectError::UnsupportedVersion {
+                version: self.version,
+            });
+        }
+
+        let services = self.services.len();
+        if self.threshold == 0 || self.threshold as usize > services {
+            return Err(EncryptedObjectError::InvalidThreshold {
+                threshold: self.threshold,
+                services,
+            });
+        }
+
+        let shares = match &self.encrypted_shares {
+            IBEEncryptions::BonehFranklinBLS12381 {
+                encrypted_shares, ..
+            } => encrypted_shares.len(),
+        };
+        if shares != services {
+            return Err(EncryptedObjectError::ShareCountMismatch { shares, services });
+        }
+
+        let mut seen = HashSet::with_capacity(services);
+        for (object_id, share_index) in self.services.iter() {
+            if !seen.insert((*object_id, *share_index)) {
+                return Err(EncryptedObjectError::DuplicateService {
+                    object_id: *object_id,
+                    share_index: *share_index,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Summarize the object without decrypting it. The result displays as a human-readable
+    /// report and serializes to JSON.
+    pub fn describe(&self) -> EncryptedObjectDescription {
+        EncryptedObjectDescription {
+            version: self.version,
+            package_id: self.package_id,
+            id: hex::encode(&self.id),
+            full_id: hex::encode(self.full_id()),
+            threshold: self.threshold,
+            services: self
+                .services
+                .iter()
+                .map(|(object_id, share_index)| ServiceDescription {
+                    object_id: *object_id,
+                    share_index: *share_index,
+                })
+                .collect(),
+            dem_type: self.dem_type(),
+            ciphertext_len: self.ciphertext_len(),
+            aad_len: self.aad().map(<[u8]>::len),
+        }
+    }
+}
+
 impl From<seal_crypto::EncryptedObject> for EncryptedObject {
     fn from(value: seal_crypto::EncryptedObject) -> Self {
         let services = value
@@ -99,7 +307,7 @@ impl FetchKeyRequest {
     pub fn to_json_string(&self) -> Result<String, serde_json::Error> {
         let sig_base64 = self.certificate.signature.to_base64();
 
-        let json = serde_json::json!({
+        let mut json = serde_json::json!({
             "ptb": self.ptb,
             "enc_key": self.enc_key,
             "enc_verification_key": self.enc_verification_key,
@@ -110,10 +318,14 @@ impl FetchKeyRequest {
                 "creation_time": self.certificate.creation_time,
                 "ttl_min": self.certificate.ttl_min,
                 "signature": sig_base64,
-                "mvr_name": self.certificate.mvr_name,
             }
         });
 
+        // Key servers expect no `mvr_name` field at all when unset, as the TypeScript SDK sends.
+        if let Some(mvr_name) = &self.certificate.mvr_name {
+            json["certificate"]["mvr_name"] = mvr_name.as_str().into();
+        }
+
         serde_json::to_string(&json)
     }
 }
@@ -127,6 +339,12 @@ impl<G: GroupElement> From<Encryption<G>> for elgamal::Encryption<G> {
     }
 }
 
+impl<G: GroupElement> From<elgamal::Encryption<G>> for Encryption<G> {
+    fn from(value: elgamal::Encryption<G>) -> Self {
+        Self(value.0, value.1)
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 pub struct DecryptionKey {
     pub id: Vec<u8>,
@@ -138,6 +356,38 @@ pub struct FetchKeyResponse {
     pub decryption_keys: Vec<DecryptionKey>,
 }
 
+/// Domain separation tag of the key servers' proof of possession.
+pub const DST_POP: &[u8] = b"SUI-SEAL-IBE-BLS12381-POP-00";
+
+/// Response of a key server's `/v1/service` endpoint.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ServiceResponse {
+    pub service_id: ObjectID,
+    pub pop: G1Element,
+}
+
+/// Verify a key server's proof of possession of the master key behind `public_key`.
+///
+/// The proof is a BLS signature over `DST_POP || public_key || message`, where the key servers
+/// use their object id as `message`.
+pub fn verify_proof_of_possession(
+    pop: &G1Element,
+    public_key: &G2Element,
+    message: &[u8],
+) -> FastCryptoResult<()> {
+    let mut full_message = DST_POP.to_vec();
+    full_message.extend(public_key.to_byte_array());
+    full_message.extend(message);
+
+    if pop.pairing(&G2Element::generator())
+        == G1Element::hash_to_group_element(&full_message).pairing(public_key)
+    {
+        Ok(())
+    } else {
+        Err(FastCryptoError::InvalidProof)
+    }
+}
+
 pub fn seal_decrypt_all_objects(
     enc_secret: &SecretKey<fastcrypto::groups::bls12381::G1Element>,
     seal_responses: &[(ObjectID, FetchKeyResponse)],
@@ -228,3 +478,105 @@ pub fn seal_decrypt_all_objects(
 
     Ok(decrypted_results)
 }
+
+/// Same as `seal_crypto::seal_encrypt`, drawing the base key, the key shares and the IBE
+/// randomness from `rng` instead of `thread_rng()`.
+///
+/// With the same `rng` state and inputs, the returned object and key are byte-for-byte
+/// identical, which makes encryptions reproducible. Returns the encrypted object and the
+/// symmetric key, which can be used to recover the data without key servers.
+pub fn seal_encrypt_with_rng<R: AllowedRng>(
+    package_id: ObjectID,
+    id: Vec<u8>,
+    key_servers: Vec<ObjectID>,
+    public_keys: &IBEPublicKeys,
+    threshold: u8,
+    encryption_input: EncryptionInput,
+    rng: &mut R,
+) -> FastCryptoResult<(EncryptedObject, [u8; KEY_SIZE])> {
+    let number_of_shares = key_servers.len();
+    if threshold == 0
+        || threshold as usize > number_of_shares
+        || number_of_shares > u8::MAX as usize
+    {
+        return Err(FastCryptoError::InvalidInput);
+    }
+
+    let full_id = create_full_id(&package_id.0, &id);
+    let upstream_key_servers: Vec<seal_crypto::ObjectID> =
+        key_servers.iter().map(|e| (*e).into()).collect();
+
+    let base_key = generate_random_bytes(rng);
+    let SecretSharing { indices, shares } =
+        tss::split(rng, base_key, threshold, number_of_shares as u8)?;
+    let services: Vec<(ObjectID, u8)> = key_servers.into_iter().zip(indices).collect();
+    let upstream_services: Vec<(seal_crypto::ObjectID, u8)> =
+        services.iter().map(|(id, i)| ((*id).into(), *i)).collect();
+
+    let encrypted_shares = match public_keys {
+        IBEPublicKeys::BonehFranklinBLS12381(pks) => {
+            if pks.len() != number_of_shares {
+                return Err(FastCryptoError::InvalidInput);
+            }
+            let randomness = ibe::Randomness::rand(rng);
+
+            // The share index is part of the IBE info, so shares for the same key server are
+            // encrypted independently.
+            let encrypted_shares = ibe::encrypt_batched_deterministic(
+                &randomness,
+                &shares,
+                pks,
+                &full_id,
+                &upstream_services,
+            )?;
+            let nonce = G2Element::generator() * randomness;
+            let encrypted_randomness = ibe::encrypt_randomness(
+                &randomness,
+                &derive_key(
+                    KeyPurpose::EncryptedRandomness,
+                    &base_key,
+                    &encrypted_shares,
+                    threshold,
+                    &upstream_key_servers,
+                ),
+            );
+            IBEEncryptions::BonehFranklinBLS12381 {
+                nonce,
+                encrypted_shares,
+                encrypted_randomness,
+            }
+        }
+    };
+
+    let dem_key = derive_key(
+        KeyPurpose::DEM,
+        &base_key,
+        encrypted_shares.ciphertexts(),
+        threshold,
+        &upstream_key_servers,
+    );
+    let ciphertext = match encryption_input {
+        EncryptionInput::Aes256Gcm { data, aad } => Ciphertext::Aes256Gcm {
+            blob: Aes256Gcm::encrypt(&data, aad.as_deref().unwrap_or(&[]), &dem_key),
+            aad,
+        },
+        EncryptionInput::Hmac256Ctr { data, aad } => {
+            let (blob, mac) = Hmac256Ctr::encrypt(&data, aad.as_deref().unwrap_or(&[]), &dem_key);
+            Ciphertext::Hmac256Ctr { blob, mac, aad }
+        }
+        EncryptionInput::Plain => Ciphertext::Plain,
+    };
+
+    Ok((
+        EncryptedObject {
+            version: ENCRYPTED_OBJECT_VERSION,
+            package_id,
+            id,
+            services,
+            threshold,
+            encrypted_shares,
+            ciphertext,
+        },
+        dem_key,
+    ))
+}
@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::generic_types::ObjectID;
+use crate::generic_types::{ObjectID, SuiAddress};
 use fastcrypto::error::FastCryptoError;
+#[cfg(feature = "reqwest")]
 use http::header::{InvalidHeaderName, InvalidHeaderValue};
 use std::convert::Infallible;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -42,10 +44,54 @@ pub enum SealClientError {
     #[error("Sui client error: {0}")]
     SuiClient(#[from] crate::native_sui_sdk::client::sui_client::SuiClientError),
 
+    #[error("JSON-RPC Sui client error: {0}")]
+    JsonRpcSuiClient(#[from] crate::json_rpc::sui_client::JsonRpcSuiClientError),
+
+    #[cfg(feature = "test-utils")]
+    #[error("In-memory Sui client error: {0}")]
+    InMemorySuiClient(#[from] crate::in_memory_sui_client::InMemorySuiClientError),
+
+    #[cfg(feature = "graphql")]
+    #[error("GraphQL Sui client error: {0}")]
+    GraphQlSuiClient(#[from] crate::graphql::sui_client::GraphQlSuiClientError),
+
+    #[cfg(feature = "grpc")]
+    #[error("gRPC Sui client error: {0}")]
+    GrpcSuiClient(#[from] crate::grpc::sui_client::GrpcSuiClientError),
+
     #[cfg(feature = "reqwest")]
     #[error("Reqwest error: {0}")]
     Reqwest(#[from] ReqwestError),
 
+    #[error("Invalid client configuration: {0}")]
+    Builder(#[from] crate::builder::SealClientBuilderError),
+
+    #[error("Key server {object_id} is missing from the batched key server lookup")]
+    MissingKeyServerInfo { object_id: ObjectID },
+
+    #[error(
+        "Key server {object_id} belongs to package {package_id}, which is not a trusted Seal package"
+    )]
+    UntrustedKeyServerPackage {
+        object_id: ObjectID,
+        package_id: ObjectID,
+    },
+
+    #[error("Error while verifying key server at {url}: HTTP {status} - {response}")]
+    ErrorWhileVerifyingKeyServer {
+        url: String,
+        status: u16,
+        response: String,
+    },
+
+    #[error(
+        "Cannot verify the key server at {url}: the HttpClient does not implement `get`, which key server verification requires"
+    )]
+    HttpGetNotImplemented { url: String },
+
+    #[error("Key server {object_id} at {url} failed to prove possession of its on-chain key")]
+    KeyServerVerificationFailed { object_id: ObjectID, url: String },
+
     #[error("Error while fetching derived keys from {url}: HTTP {status} - {response}")]
     ErrorWhileFetchingDerivedKeys {
         url: String,
@@ -53,9 +99,37 @@ pub enum SealClientError {
         response: String,
     },
 
+    #[error("Request to {url} timed out after {timeout:?}")]
+    KeyServerTimeout { url: String, timeout: Duration },
+
     #[error("Insufficient keys: received {received}, but threshold is {threshold}")]
     InsufficientKeys { received: usize, threshold: u8 },
 
+    #[error("Encrypted object error: {0}")]
+    EncryptedObject(#[from] EncryptedObjectError),
+
+    #[error(
+        "Encrypted object is for package {object_package_id}, but the session key is for package {session_package_id}"
+    )]
+    PackageIdMismatch {
+        object_package_id: ObjectID,
+        session_package_id: ObjectID,
+    },
+
+    #[error("Approve transaction error: {0}")]
+    ApproveTransaction(#[from] ApproveTransactionError),
+
+    #[error("Missing encrypted object")]
+    MissingEncryptedObject,
+
+    #[cfg(feature = "test-utils")]
+    #[error("Replay error: {0}")]
+    Replay(#[from] crate::record_replay::ReplayError),
+
+    #[cfg(feature = "test-utils")]
+    #[error("Injected connection error for {url}")]
+    InjectedConnectionError { url: String },
+
     #[error("Missing decrypted object")]
     MissingDecryptedObject,
 
@@ -66,6 +140,66 @@ pub enum SealClientError {
     UnknownError(#[from] anyhow::Error),
 }
 
+#[derive(Debug, Error)]
+pub enum ApproveTransactionError {
+    #[error("BCS error: {0}")]
+    BCS(#[from] bcs::Error),
+
+    #[error("The approve transaction has no seal_approve call")]
+    NoSealApproveCall,
+
+    #[error("Command {index} of the approve transaction is not a move call")]
+    NotMoveCall { index: usize },
+
+    #[error(
+        "Command {index} of the approve transaction calls {function}, not a seal_approve function"
+    )]
+    NotSealApprove { index: usize, function: String },
+
+    #[error(
+        "Command {index} of the approve transaction does not take the id as a pure first argument"
+    )]
+    MissingId { index: usize },
+
+    #[error("The approve transaction calls both package {first} and package {other}")]
+    MultiplePackages { first: ObjectID, other: ObjectID },
+
+    #[error(
+        "The approve transaction calls package {package_id}, but the session key is for package {session_package_id}"
+    )]
+    PackageMismatch {
+        package_id: ObjectID,
+        session_package_id: ObjectID,
+    },
+}
+
+#[derive(Debug, Error)]
+pub enum EncryptedObjectError {
+    #[error("BCS error: {0}")]
+    BCS(#[from] bcs::Error),
+
+    #[error("HEX deserialization error: {0}")]
+    HEXDeserialization(#[from] hex::FromHexError),
+
+    #[error("Base64 decode error: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    #[error("Unsupported encrypted object version {version}")]
+    UnsupportedVersion { version: u8 },
+
+    #[error("Invalid threshold {threshold} for {services} key servers")]
+    InvalidThreshold { threshold: u8, services: usize },
+
+    #[error("Encrypted object has {shares} encrypted shares for {services} key servers")]
+    ShareCountMismatch { shares: usize, services: usize },
+
+    #[error("Key server {object_id} appears twice with share index {share_index}")]
+    DuplicateService {
+        object_id: ObjectID,
+        share_index: u8,
+    },
+}
+
 #[cfg(feature = "reqwest")]
 #[derive(Debug, Error)]
 pub enum ReqwestError {
@@ -91,12 +225,23 @@ pub enum SessionKeyError {
         ttl_min: u16,
     },
 
+    #[error(
+        "Signer address {claimed} does not match the address {derived} derived from its public key"
+    )]
+    SignerAddressMismatch {
+        claimed: SuiAddress,
+        derived: SuiAddress,
+    },
+
     #[error("BCS error: {0}")]
     BCS(#[from] bcs::Error),
 
     #[error("FastCrypto error: {0}")]
     FastCrypto(#[from] FastCryptoError),
 
+    #[error("Keypair signer error: {0}")]
+    KeypairSigner(#[from] crate::signer::keypair::KeypairSignerError),
+
     #[cfg(feature = "native-sui-sdk")]
     #[error("Wallet context error: {0}")]
     WalletContext(#[from] crate::native_sui_sdk::signer::wallet_context::WalletContextError),
@@ -107,3 +252,24 @@ impl From<Infallible> for SessionKeyError {
         panic!()
     }
 }
+
+impl From<Infallible> for SealClientError {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl<E> From<crate::fault_injection::FaultInjectionError<E>> for SealClientError
+where
+    SealClientError: From<E>,
+{
+    fn from(value: crate::fault_injection::FaultInjectionError<E>) -> Self {
+        match value {
+            crate::fault_injection::FaultInjectionError::Inner(err) => err.into(),
+            crate::fault_injection::FaultInjectionError::ConnectionError { url } => {
+                SealClientError::InjectedConnectionError { url }
+            }
+        }
+    }
+}
@@ -0,0 +1,300 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [`HttpClient`] decorator injecting key server faults, for resilience tests.
+//!
+//! [`FaultInjectingHttpClient`] wraps any [`HttpClient`] and applies [`FaultRule`]s to the
+//! requests whose URL matches, always or with a given probability. Faults simulate latency,
+//! hanging or crashed servers, error statuses, truncated responses and corrupted decryption
+//! keys without touching the servers themselves.
+
+use crate::http_client::{HttpClient, HttpResponse, PostResponse};
+use async_trait::async_trait;
+use base64::Engine;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum FaultInjectionError<E> {
+    #[error("{0}")]
+    Inner(E),
+
+    #[error("Injected connection error for {url}")]
+    ConnectionError { url: String },
+}
+
+/// Misbehavior injected by a [`FaultRule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HttpFault {
+    /// Wait before sending the request.
+    Latency(Duration),
+    /// Never answer. Pair it with a request timeout on the client.
+    Hang,
+    /// Fail with [`FaultInjectionError::ConnectionError`] without sending the request, as if
+    /// the server were down.
+    ConnectionError,
+    /// Answer `status` without sending the request, with a `Retry-After` header in seconds if
+    /// set. Use `5xx` or `429` to exercise retries.
+    Status {
+        status: u16,
+        retry_after: Option<u64>,
+    },
+    /// Cut the response body in half, leaving invalid JSON.
+    TruncatedBody,
+    /// Flip one bit of every encrypted key of a `/v1/fetch_key` response, so that the client
+    /// rejects the server's answer.
+    FlippedDecryptionKeys,
+}
+
+/// [`HttpFault`] applied to the requests whose URL contains a pattern, with a probability.
+#[derive(Debug, Clone)]
+pub struct FaultRule {
+    fault: HttpFault,
+    url_pattern: Option<String>,
+    probability: f64,
+}
+
+impl FaultRule {
+    /// Rule applying `fault` to every request.
+    pub fn new(fault: HttpFault) -> Self {
+        FaultRule {
+            fault,
+            url_pattern: None,
+            probability: 1.0,
+        }
+    }
+
+    /// Only apply the fault to URLs containing `pattern`, e.g. a key server's base URL.
+    pub fn for_url(mut self, pattern: impl Into<String>) -> Self {
+        self.url_pattern = Some(pattern.into());
+        self
+    }
+
+    /// Apply the fault to a random share of the matching requests, between `0.0` and `1.0`.
+    pub fn with_probability(mut self, probability: f64) -> Self {
+        self.probability = probability.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn fault(&self) -> &HttpFault {
+        &self.fault
+    }
+
+    fn matches(&self, url: &str) -> bool {
+        self.url_pattern
+            .as_deref()
+            .is_none_or(|pattern| url.contains(pattern))
+    }
+}
+
+/// [`HttpClient`] wrapping another one and applying [`FaultRule`]s to its requests.
+///
+/// Every matching rule is rolled independently, in insertion order. Latency adds up, and the
+/// first fault preventing the request (hang, connection error or status) short-circuits the
+/// remaining ones. Response faults apply to the inner client's successful responses only.
+pub struct FaultInjectingHttpClient<H> {
+    inner: H,
+    rules: Vec<FaultRule>,
+    rng: Mutex<StdRng>,
+}
+
+impl<H> FaultInjectingHttpClient<H> {
+    pub fn new(inner: H) -> Self {
+        FaultInjectingHttpClient {
+            inner,
+            rules: vec![],
+            rng: Mutex::new(StdRng::from_entropy()),
+        }
+    }
+
+    /// Seed the random rolls of probabilistic rules, for reproducible runs.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    pub fn with_rule(mut self, rule: FaultRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Always apply `fault` to URLs containing `url_pattern`.
+    pub fn with_fault(self, url_pattern: impl Into<String>, fault: HttpFault) -> Self {
+        self.with_rule(FaultRule::new(fault).for_url(url_pattern))
+    }
+
+    /// Apply `fault` to any request with the given probability.
+    pub fn with_random_fault(self, probability: f64, fault: HttpFault) -> Self {
+        self.with_rule(FaultRule::new(fault).with_probability(probability))
+    }
+
+    pub fn inner(&self) -> &H {
+        &self.inner
+    }
+
+    fn triggered_faults(&self, url: &str) -> Vec<HttpFault> {
+        let mut rng = self.rng.lock().unwrap();
+
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(url))
+            .filter(|rule| rule.probability >= 1.0 || rng.gen_bool(rule.probability))
+            .map(|rule| rule.fault.clone())
+            .collect()
+    }
+
+    async fn send<Fut, E>(
+        &self,
+        url: &str,
+        request: Fut,
+    ) -> Result<HttpResponse, FaultInjectionError<E>>
+    where
+        Fut: Future<Output = Result<HttpResponse, E>>,
+    {
+        let faults = self.triggered_faults(url);
+
+        for fault in faults.iter() {
+            match fault {
+                HttpFault::Latency(latency) => tokio::time::sleep(*latency).await,
+                HttpFault::Hang => std::future::pending::<()>().await,
+                HttpFault::ConnectionError => {
+                    return Err(FaultInjectionError::ConnectionError {
+                        url: url.to_string(),
+                    });
+                }
+                HttpFault::Status {
+                    status,
+                    retry_after,
+                } => {
+                    let response = HttpResponse::new(*status, "injected fault");
+
+                    return Ok(match retry_after {
+                        Some(seconds) => response.with_header("retry-after", seconds.to_string()),
+                        None => response,
+                    });
+                }
+                HttpFault::TruncatedBody | HttpFault::FlippedDecryptionKeys => {}
+            }
+        }
+
+        let mut response = request.await.map_err(FaultInjectionError::Inner)?;
+        if !response.is_success() {
+            return Ok(response);
+        }
+
+        for fault in faults.iter() {
+            match fault {
+                HttpFault::TruncatedBody => {
+                    let mut cut = response.text.len() / 2;
+                    while !response.text.is_char_boundary(cut) {
+                        cut -= 1;
+                    }
+                    response.text.truncate(cut);
+                }
+                HttpFault::FlippedDecryptionKeys => {
+                    response.text = flip_decryption_keys(&response.text);
+                }
+                _ => {}
+            }
+        }
+
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl<H> HttpClient for FaultInjectingHttpClient<H>
+where
+    H: HttpClient + Send,
+    H::PostError: Send,
+{
+    type PostError = FaultInjectionError<H::PostError>;
+
+    async fn post<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<PostResponse, Self::PostError> {
+        self.post_with_headers(url, headers, body)
+            .await
+            .map(Into::into)
+    }
+
+    async fn get(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<PostResponse, Self::PostError> {
+        self.get_with_headers(url, headers).await.map(Into::into)
+    }
+
+    async fn post_with_headers<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<HttpResponse, Self::PostError> {
+        self.send(url, self.inner.post_with_headers(url, headers, body))
+            .await
+    }
+
+    async fn get_with_headers(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<HttpResponse, Self::PostError> {
+        self.send(url, self.inner.get_with_headers(url, headers))
+            .await
+    }
+}
+
+/// Flip the last bit of both group elements of every `decryption_keys[].encrypted_key`.
+///
+/// Bodies that are not fetch key responses are returned untouched.
+fn flip_decryption_keys(text: &str) -> String {
+    let Ok(mut json) = serde_json::from_str::<serde_json::Value>(text) else {
+        return text.to_string();
+    };
+
+    let Some(decryption_keys) = json
+        .get_mut("decryption_keys")
+        .and_then(serde_json::Value::as_array_mut)
+    else {
+        return text.to_string();
+    };
+
+    let engine = base64::engine::general_purpose::STANDARD;
+    for element in decryption_keys
+        .iter_mut()
+        .filter_map(|key| key.get_mut("encrypted_key"))
+        .filter_map(serde_json::Value::as_array_mut)
+        .flatten()
+    {
+        let Some(mut bytes) = element.as_str().and_then(|value| engine.decode(value).ok()) else {
+            continue;
+        };
+
+        if let Some(last) = bytes.last_mut() {
+            *last ^= 1;
+        }
+        *element = serde_json::Value::String(engine.encode(bytes));
+    }
+
+    json.to_string()
+}
@@ -160,3 +160,18 @@ impl<'de> Deserialize<'de> for SuiAddress {
 pub trait BCSSerializableProgrammableTransaction {
     fn to_bcs_bytes(&self) -> Result<Vec<u8>, SealClientError>;
 }
+
+impl<T> BCSSerializableProgrammableTransaction for &T
+where
+    T: BCSSerializableProgrammableTransaction + ?Sized,
+{
+    fn to_bcs_bytes(&self) -> Result<Vec<u8>, SealClientError> {
+        (**self).to_bcs_bytes()
+    }
+}
+
+impl BCSSerializableProgrammableTransaction for sui_sdk_types::ProgrammableTransaction {
+    fn to_bcs_bytes(&self) -> Result<Vec<u8>, SealClientError> {
+        Ok(bcs::to_bytes(self)?)
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_client::{BaseSealClient, DerivedKeys, KeyServerInfo};
+use crate::cache::NoCache;
+use crate::cache_key::{DerivedKeyCacheKey, KeyServerInfoCacheKey};
+use crate::graphql::sui_client::GraphQlSuiClient;
+use crate::http_client::HttpClient;
+use crate::sui_client::SuiClient;
+use reqwest::Client;
+
+/// [`BaseSealClient`] specialization that reads key servers through [`GraphQlSuiClient`].
+///
+/// Both the Sui GraphQL queries and the key server requests go through `reqwest::Client`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use seal_sdk_rs::graphql::seal_client::GraphQlSealClient;
+///
+/// let seal_client = GraphQlSealClient::new("https://sui-testnet.mystenlabs.com/graphql");
+/// ```
+pub type GraphQlSealClient = BaseSealClient<
+    NoCache<KeyServerInfoCacheKey, KeyServerInfo>,
+    NoCache<DerivedKeyCacheKey, DerivedKeys>,
+    <GraphQlSuiClient<Client> as SuiClient>::Error,
+    GraphQlSuiClient<Client>,
+    <Client as HttpClient>::PostError,
+    Client,
+>;
+
+impl GraphQlSealClient {
+    pub fn new<S: Into<String>>(graphql_url: S) -> GraphQlSealClient {
+        Self::new_with_http_client(graphql_url, Client::new())
+    }
+
+    /// Same as [`new`](Self::new), with a caller-configured `reqwest::Client`.
+    ///
+    /// The client is shared by the GraphQL queries and the key server requests.
+    pub fn new_with_http_client<S: Into<String>>(
+        graphql_url: S,
+        http_client: Client,
+    ) -> GraphQlSealClient {
+        BaseSealClient::new_custom(
+            ().into(),
+            ().into(),
+            GraphQlSuiClient::new(http_client.clone(), graphql_url),
+            http_client,
+        )
+    }
+}
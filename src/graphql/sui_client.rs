@@ -0,0 +1,276 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_client::KeyServerInfo;
+use crate::generic_types::ObjectID;
+use crate::http_client::HttpClient;
+use crate::key_server_layout::{
+    KeyServerLayoutError, ObjectRead, resolve_key_server, resolve_key_servers,
+};
+use crate::sui_client::SuiClient;
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+use std::fmt::Display;
+use thiserror::Error;
+
+/// Selection shared by every object aliased in [`objects_query`].
+const OBJECT_FRAGMENT: &str = r#"fragment KeyServerObject on Object {
+  asMoveObject {
+    contents {
+      type { repr }
+      bcs
+    }
+  }
+}"#;
+
+#[derive(Debug, Error)]
+pub enum GraphQlSuiClientError {
+    #[error("HTTP error while calling the Sui GraphQL service: {message}")]
+    Http { message: String },
+
+    #[error("Sui GraphQL service {url} answered with HTTP {status}: {response}")]
+    UnexpectedStatus {
+        url: String,
+        status: u16,
+        response: String,
+    },
+
+    #[error("JSON error: {0}")]
+    JSON(#[from] serde_json::Error),
+
+    #[error("Sui GraphQL errors: {messages}")]
+    GraphQl { messages: String },
+
+    #[error("Missing key server field: {field_name}")]
+    MissingKeyServerField { field_name: String },
+
+    #[error("Base64 decode error: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    #[error("Key server layout error: {0}")]
+    KeyServerLayout(#[from] KeyServerLayoutError),
+}
+
+/// [`SuiClient`] reading key servers from a Sui GraphQL service through any [`HttpClient`].
+///
+/// The client reads the `KeyServer` object and its `u64` dynamic fields by id, batching them
+/// into a single aliased query, and decodes their BCS contents with the layouts from
+/// [`key_server_layout`](crate::key_server_layout). Like the other backends it tries the
+/// newest layout first and falls back to older ones, so both independent and committee key
+/// servers resolve to the same [`KeyServerInfo`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use seal_sdk_rs::graphql::sui_client::GraphQlSuiClient;
+///
+/// let sui_client = GraphQlSuiClient::new(
+///     reqwest::Client::new(),
+///     "https://sui-testnet.mystenlabs.com/graphql",
+/// );
+/// ```
+#[derive(Clone)]
+pub struct GraphQlSuiClient<Http> {
+    http_client: Http,
+    graphql_url: String,
+}
+
+impl<Http> GraphQlSuiClient<Http> {
+    pub fn new<S: Into<String>>(http_client: Http, graphql_url: S) -> Self {
+        Self {
+            http_client,
+            graphql_url: graphql_url.into(),
+        }
+    }
+
+    pub fn graphql_url(&self) -> &str {
+        &self.graphql_url
+    }
+}
+
+#[async_trait]
+impl<Http> SuiClient for GraphQlSuiClient<Http>
+where
+    Http: HttpClient + Send + Sync,
+    Http::PostError: Display,
+{
+    type Error = GraphQlSuiClientError;
+
+    async fn get_key_server_info(
+        &self,
+        key_server_id: [u8; 32],
+    ) -> Result<KeyServerInfo, Self::Error> {
+        resolve_key_server(ObjectID(key_server_id), |object_ids| {
+            self.multi_get_objects(object_ids)
+        })
+        .await
+    }
+
+    async fn get_key_servers_info(
+        &self,
+        key_server_ids: Vec<[u8; 32]>,
+    ) -> Result<Vec<KeyServerInfo>, Self::Error> {
+        let key_server_ids = key_server_ids.into_iter().map(ObjectID).collect::<Vec<_>>();
+
+        resolve_key_servers(&key_server_ids, |object_ids| {
+            self.multi_get_objects(object_ids)
+        })
+        .await
+    }
+}
+
+impl<Http> GraphQlSuiClient<Http>
+where
+    Http: HttpClient + Send + Sync,
+    Http::PostError: Display,
+{
+    /// Read the type and BCS contents of several objects with one aliased query.
+    async fn multi_get_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> Result<Vec<ObjectRead>, GraphQlSuiClientError> {
+        let variables = object_ids
+            .iter()
+            .enumerate()
+            .map(|(index, object_id)| (format!("id{index}"), json!(object_id.to_string())))
+            .collect::<Map<_, _>>();
+
+        let data = self
+            .query(&objects_query(object_ids.len()), Value::Object(variables))
+            .await?;
+
+        (0..object_ids.len())
+            .map(|index| object_read(&data[format!("object{index}")]))
+            .collect()
+    }
+
+    async fn query(&self, query: &str, variables: Value) -> Result<Value, GraphQlSuiClientError> {
+        let body = json!({
+            "query": query,
+            "variables": variables,
+        });
+
+        let mut headers = HashMap::new();
+        headers.insert("Content-Type".to_string(), "application/json".to_string());
+
+        let response = self
+            .http_client
+            .post(&self.graphql_url, headers, body)
+            .await
+            .map_err(|err| GraphQlSuiClientError::Http {
+                message: err.to_string(),
+            })?;
+
+        if !response.is_success() {
+            return Err(GraphQlSuiClientError::UnexpectedStatus {
+                url: self.graphql_url.clone(),
+                status: response.status,
+                response: response.text,
+            });
+        }
+
+        let mut response: Value = serde_json::from_str(&response.text)?;
+
+        if let Some(errors) = response
+            .get("errors")
+            .and_then(Value::as_array)
+            .filter(|errors| !errors.is_empty())
+        {
+            let messages = errors
+                .iter()
+                .map(|error| {
+                    error
+                        .get("message")
+                        .and_then(Value::as_str)
+                        .map(str::to_string)
+                        .unwrap_or_else(|| error.to_string())
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+
+            return Err(GraphQlSuiClientError::GraphQl { messages });
+        }
+
+        response
+            .get_mut("data")
+            .map(Value::take)
+            .filter(|data| !data.is_null())
+            .ok_or_else(|| missing_field("data"))
+    }
+}
+
+/// Query reading `count` objects, aliased `object0`, `object1`, ...
+fn objects_query(count: usize) -> String {
+    let variables = (0..count)
+        .map(|index| format!("$id{index}: SuiAddress!"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let selections = (0..count)
+        .map(|index| {
+            format!("  object{index}: object(address: $id{index}) {{ ...KeyServerObject }}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!("query KeyServerObjects({variables}) {{\n{selections}\n}}\n\n{OBJECT_FRAGMENT}")
+}
+
+/// Convert one aliased `object` selection.
+///
+/// The service returns `null` for objects that do not exist at their latest version, deleted
+/// ones included, so this backend reports both as [`ObjectRead::NotFound`].
+fn object_read(object: &Value) -> Result<ObjectRead, GraphQlSuiClientError> {
+    if object.is_null() {
+        return Ok(ObjectRead::NotFound);
+    }
+
+    let Some(contents) = object
+        .get("asMoveObject")
+        .and_then(|move_object| move_object.get("contents"))
+        .filter(|contents| !contents.is_null())
+    else {
+        // Only packages lack Move object contents.
+        return Ok(ObjectRead::Found {
+            object_type: "package".to_string(),
+            contents: Vec::new(),
+        });
+    };
+
+    let object_type = contents
+        .get("type")
+        .and_then(|object_type| object_type.get("repr"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| missing_field("asMoveObject.contents.type.repr"))?
+        .to_string();
+
+    let bcs = contents
+        .get("bcs")
+        .and_then(Value::as_str)
+        .ok_or_else(|| missing_field("asMoveObject.contents.bcs"))?;
+
+    Ok(ObjectRead::Found {
+        object_type,
+        contents: STANDARD.decode(bcs)?,
+    })
+}
+
+fn missing_field(field_name: &str) -> GraphQlSuiClientError {
+    GraphQlSuiClientError::MissingKeyServerField {
+        field_name: field_name.to_string(),
+    }
+}
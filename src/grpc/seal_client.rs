@@ -0,0 +1,66 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_client::{BaseSealClient, DerivedKeys, KeyServerInfo};
+use crate::cache::NoCache;
+use crate::cache_key::{DerivedKeyCacheKey, KeyServerInfoCacheKey};
+use crate::grpc::sui_client::{GrpcSuiClient, SuiRpcClient};
+use crate::http_client::HttpClient;
+use crate::sui_client::SuiClient;
+use reqwest::Client;
+
+/// [`BaseSealClient`] specialization that reads key servers through [`GrpcSuiClient`].
+///
+/// Key server metadata comes from the Sui gRPC API and the key server requests go through
+/// `reqwest::Client`, so this alias needs neither `sui_sdk` nor the JSON-RPC endpoints.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use seal_sdk_rs::grpc::seal_client::GrpcSealClient;
+/// use seal_sdk_rs::grpc::sui_client::SuiRpcClient;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let seal_client = GrpcSealClient::new(SuiRpcClient::new("https://fullnode.testnet.sui.io")?);
+/// # Ok(())
+/// # }
+/// ```
+pub type GrpcSealClient = BaseSealClient<
+    NoCache<KeyServerInfoCacheKey, KeyServerInfo>,
+    NoCache<DerivedKeyCacheKey, DerivedKeys>,
+    <GrpcSuiClient as SuiClient>::Error,
+    GrpcSuiClient,
+    <Client as HttpClient>::PostError,
+    Client,
+>;
+
+impl GrpcSealClient {
+    pub fn new(sui_rpc_client: SuiRpcClient) -> GrpcSealClient {
+        Self::new_with_http_client(sui_rpc_client, Client::new())
+    }
+
+    /// Same as [`new`](Self::new), with a caller-configured `reqwest::Client` for the key
+    /// server requests.
+    pub fn new_with_http_client(
+        sui_rpc_client: SuiRpcClient,
+        http_client: Client,
+    ) -> GrpcSealClient {
+        BaseSealClient::new_custom(
+            ().into(),
+            ().into(),
+            GrpcSuiClient::new(sui_rpc_client),
+            http_client,
+        )
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_client::KeyServerInfo;
+use crate::generic_types::ObjectID;
+use crate::key_server_layout::{
+    KeyServerLayoutError, ObjectRead, resolve_key_server, resolve_key_servers,
+};
+use crate::sui_client::SuiClient;
+use async_trait::async_trait;
+use futures::future::join_all;
+use sui_rpc::field::{FieldMask, FieldMaskUtil};
+use sui_rpc::proto::sui::rpc::v2::{GetObjectRequest, GetObjectResponse};
+use thiserror::Error;
+
+pub use sui_rpc::Client as SuiRpcClient;
+
+/// `tonic::Code::NotFound`.
+const GRPC_NOT_FOUND: i32 = 5;
+
+#[derive(Debug, Error)]
+pub enum GrpcSuiClientError {
+    #[error("Sui gRPC error: {message}")]
+    Rpc { message: String },
+
+    #[error("No object data from the Sui RPC for object {object_id}")]
+    NoObjectDataFromTheSuiRPC { object_id: ObjectID },
+
+    #[error("Key server layout error: {0}")]
+    KeyServerLayout(#[from] KeyServerLayoutError),
+}
+
+/// [`SuiClient`] built on the `sui-rpc` gRPC client from `MystenLabs/sui-rust-sdk`.
+///
+/// The client derives the object id of each `KeyServerV{n}` dynamic field locally, fetches the
+/// key server and field Move contents over gRPC and BCS-decodes them with the layouts from
+/// [`key_server_layout`](crate::key_server_layout), committee members included. Combined with
+/// `reqwest` for the key server calls, it covers encryption and decryption without the
+/// `native-sui-sdk` feature.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use seal_sdk_rs::grpc::sui_client::{GrpcSuiClient, SuiRpcClient};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let sui_client = GrpcSuiClient::new(SuiRpcClient::new("https://fullnode.testnet.sui.io")?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct GrpcSuiClient {
+    client: SuiRpcClient,
+}
+
+impl GrpcSuiClient {
+    pub fn new(client: SuiRpcClient) -> Self {
+        Self { client }
+    }
+
+    pub fn client(&self) -> &SuiRpcClient {
+        &self.client
+    }
+}
+
+impl From<SuiRpcClient> for GrpcSuiClient {
+    fn from(value: SuiRpcClient) -> Self {
+        Self::new(value)
+    }
+}
+
+#[async_trait]
+impl SuiClient for GrpcSuiClient {
+    type Error = GrpcSuiClientError;
+
+    async fn get_key_server_info(
+        &self,
+        key_server_id: [u8; 32],
+    ) -> Result<KeyServerInfo, Self::Error> {
+        resolve_key_server(ObjectID(key_server_id), |object_ids| {
+            self.get_objects(object_ids)
+        })
+        .await
+    }
+
+    async fn get_key_servers_info(
+        &self,
+        key_server_ids: Vec<[u8; 32]>,
+    ) -> Result<Vec<KeyServerInfo>, Self::Error> {
+        let key_server_ids = key_server_ids.into_iter().map(ObjectID).collect::<Vec<_>>();
+
+        resolve_key_servers(&key_server_ids, |object_ids| self.get_objects(object_ids)).await
+    }
+}
+
+impl GrpcSuiClient {
+    /// Read the type and BCS contents of several objects with concurrent `GetObject` calls.
+    async fn get_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> Result<Vec<ObjectRead>, GrpcSuiClientError> {
+        join_all(
+            object_ids
+                .into_iter()
+                .map(|object_id| self.get_object(object_id)),
+        )
+        .await
+        .into_iter()
+        .collect()
+    }
+
+    async fn get_object(&self, object_id: ObjectID) -> Result<ObjectRead, GrpcSuiClientError> {
+        let request = GetObjectRequest::new(&object_id.into())
+            .with_read_mask(FieldMask::from_paths(["object_type", "contents"]));
+
+        let mut client = self.client.clone();
+        let response = match client.ledger_client().get_object(request).await {
+            Ok(response) => response.into_inner(),
+            // Deleted objects are reported as missing too.
+            Err(status) if i32::from(status.code()) == GRPC_NOT_FOUND => {
+                return Ok(ObjectRead::NotFound);
+            }
+            Err(status) => {
+                return Err(GrpcSuiClientError::Rpc {
+                    message: status.to_string(),
+                });
+            }
+        };
+
+        decode_get_object_response(object_id, response)
+    }
+}
+
+/// Type and BCS contents of `object_id` from a `GetObject` response read with the
+/// `object_type` and `contents` mask.
+pub fn decode_get_object_response(
+    object_id: ObjectID,
+    response: GetObjectResponse,
+) -> Result<ObjectRead, GrpcSuiClientError> {
+    let object = response
+        .object
+        .ok_or(GrpcSuiClientError::NoObjectDataFromTheSuiRPC { object_id })?;
+
+    Ok(ObjectRead::Found {
+        object_type: object.object_type.unwrap_or_default(),
+        // Packages have no Move contents; their type alone rules them out as key servers.
+        contents: object
+            .contents
+            .and_then(|contents| contents.value)
+            .map(|value| value.to_vec())
+            .unwrap_or_default(),
+    })
+}
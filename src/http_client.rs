@@ -14,6 +14,14 @@
 
 use async_trait::async_trait;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Body of the `405` response returned by the default [`HttpClient::get`].
+///
+/// The seal client recognizes it and reports
+/// [`SealClientError::HttpGetNotImplemented`](crate::error::SealClientError::HttpGetNotImplemented)
+/// instead of an HTTP failure.
+pub const GET_NOT_IMPLEMENTED: &str = "HttpClient::get is not implemented";
 
 pub struct PostResponse {
     pub status: u16,
@@ -21,6 +29,13 @@ pub struct PostResponse {
 }
 
 impl PostResponse {
+    pub fn new(status: u16, text: impl Into<String>) -> Self {
+        Self {
+            status,
+            text: text.into(),
+        }
+    }
+
     pub fn is_success(&self) -> bool {
         let status = self.status;
 
@@ -28,11 +43,66 @@ impl PostResponse {
     }
 }
 
+/// [`PostResponse`] along with the response headers, returned by
+/// [`HttpClient::post_with_headers`] and [`HttpClient::get_with_headers`].
+#[derive(Debug, Clone, Default)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub text: String,
+    /// Response headers keyed by lowercase name. Repeated headers are joined with `", "`.
+    pub headers: HashMap<String, String>,
+}
+
+impl HttpResponse {
+    pub fn new(status: u16, text: impl Into<String>) -> Self {
+        Self {
+            status,
+            text: text.into(),
+            headers: HashMap::new(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.insert(name.to_ascii_lowercase(), value.into());
+        self
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Value of the `name` header, looked up case-insensitively.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+}
+
+impl From<PostResponse> for HttpResponse {
+    fn from(value: PostResponse) -> Self {
+        HttpResponse::new(value.status, value.text)
+    }
+}
+
+impl From<HttpResponse> for PostResponse {
+    fn from(value: HttpResponse) -> Self {
+        PostResponse {
+            status: value.status,
+            text: value.text,
+        }
+    }
+}
+
 /// Thin wrapper around the HTTP capabilities required by the seal client.
 ///
-/// Only simple POST semantics are needed to talk to key servers. When the crate's
-/// `client` feature is enabled (the default), we provide an adapter for `reqwest::Client`
-/// in [`reqwest::client`](crate::reqwest::client).
+/// Only simple POST semantics are needed to talk to key servers. GET is used by optional
+/// features such as key server verification and service or health endpoints, and has a default
+/// implementation, so clients that only support POST keep working. The SDK reads response
+/// headers such as `Retry-After` through [`post_with_headers`](Self::post_with_headers) and
+/// [`get_with_headers`](Self::get_with_headers), whose defaults return no headers. When the
+/// crate's `client` feature is enabled (the default), we provide an adapter for
+/// `reqwest::Client` in [`reqwest::client`](crate::reqwest::client).
 #[async_trait]
 pub trait HttpClient: Sync {
     type PostError;
@@ -43,4 +113,82 @@ pub trait HttpClient: Sync {
         headers: HashMap<String, String>,
         body: S,
     ) -> Result<PostResponse, Self::PostError>;
+
+    /// Send a GET request.
+    ///
+    /// Key server verification needs it. The default implementation makes no network call and
+    /// answers `405` with a [`GET_NOT_IMPLEMENTED`] body, which the seal client reports as
+    /// [`SealClientError::HttpGetNotImplemented`](crate::error::SealClientError::HttpGetNotImplemented).
+    /// Override it before enabling verification.
+    async fn get(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<PostResponse, Self::PostError> {
+        let _ = (url, headers);
+
+        Ok(PostResponse::new(405, GET_NOT_IMPLEMENTED))
+    }
+
+    /// Send a POST request, keeping the response headers.
+    ///
+    /// The default implementation calls [`post`](Self::post) and returns no headers.
+    async fn post_with_headers<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<HttpResponse, Self::PostError> {
+        self.post(url, headers, body).await.map(Into::into)
+    }
+
+    /// Send a GET request, keeping the response headers.
+    ///
+    /// The default implementation calls [`get`](Self::get) and returns no headers.
+    async fn get_with_headers(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<HttpResponse, Self::PostError> {
+        self.get(url, headers).await.map(Into::into)
+    }
+}
+
+#[async_trait]
+impl<T: HttpClient + Send> HttpClient for Arc<T> {
+    type PostError = T::PostError;
+
+    async fn post<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<PostResponse, Self::PostError> {
+        (**self).post(url, headers, body).await
+    }
+
+    async fn get(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<PostResponse, Self::PostError> {
+        (**self).get(url, headers).await
+    }
+
+    async fn post_with_headers<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<HttpResponse, Self::PostError> {
+        (**self).post_with_headers(url, headers, body).await
+    }
+
+    async fn get_with_headers(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<HttpResponse, Self::PostError> {
+        (**self).get_with_headers(url, headers).await
+    }
 }
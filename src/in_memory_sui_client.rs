@@ -0,0 +1,219 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [`SuiClient`] serving key servers from memory, for tests.
+//!
+//! [`InMemorySuiClient`] answers from a map of [`KeyServerInfo`], filled in code or from JSON
+//! fixtures, and can inject faults per key server to exercise caching, committee rotation and
+//! error paths deterministically.
+
+use crate::base_client::KeyServerInfo;
+use crate::generic_types::ObjectID;
+use crate::sui_client::SuiClient;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum InMemorySuiClientError {
+    #[error("Key server {object_id} not found")]
+    KeyServerNotFound { object_id: ObjectID },
+
+    #[error("Injected error for key server {object_id}: {message}")]
+    Injected {
+        object_id: ObjectID,
+        message: String,
+    },
+
+    #[error("JSON error: {0}")]
+    JSON(#[from] serde_json::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Fault injected when a key server is looked up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InMemoryFault {
+    /// Answer [`InMemorySuiClientError::KeyServerNotFound`], even if the key server is known.
+    NotFound,
+    /// Answer [`InMemorySuiClientError::Injected`] with this message.
+    Error(String),
+    /// Wait before answering normally.
+    Latency(Duration),
+}
+
+#[derive(Default)]
+struct KeyServerEntry {
+    /// Served in turn, one per lookup, to simulate an object changing between reads.
+    versions: Vec<KeyServerInfo>,
+    next_version: usize,
+    fault: Option<InMemoryFault>,
+}
+
+/// In-memory [`SuiClient`] backed by a map of [`KeyServerInfo`].
+///
+/// Lookups of unknown key servers fail with [`InMemorySuiClientError::KeyServerNotFound`].
+/// Key servers and faults can be changed through `&self` while a client built on top of it is
+/// in use, e.g. to rotate a committee between two decryptions.
+#[derive(Default)]
+pub struct InMemorySuiClient {
+    key_servers: Mutex<HashMap<ObjectID, KeyServerEntry>>,
+    latency: Option<Duration>,
+    lookups: AtomicUsize,
+}
+
+impl InMemorySuiClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a client from a JSON array of [`KeyServerInfo`].
+    pub fn from_json(json: &str) -> Result<Self, InMemorySuiClientError> {
+        let key_servers: Vec<KeyServerInfo> = serde_json::from_str(json)?;
+
+        Ok(Self::new().with_key_servers(key_servers))
+    }
+
+    /// Build a client from a JSON fixture file containing an array of [`KeyServerInfo`].
+    pub fn from_json_file(path: impl AsRef<Path>) -> Result<Self, InMemorySuiClientError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn with_key_server(self, key_server: KeyServerInfo) -> Self {
+        self.set_key_server(key_server);
+        self
+    }
+
+    pub fn with_key_servers(self, key_servers: impl IntoIterator<Item = KeyServerInfo>) -> Self {
+        for key_server in key_servers {
+            self.set_key_server(key_server);
+        }
+        self
+    }
+
+    /// Serve `versions` in turn for `object_id`, cycling back to the first one after the last.
+    pub fn with_key_server_versions(
+        self,
+        object_id: ObjectID,
+        versions: impl IntoIterator<Item = KeyServerInfo>,
+    ) -> Self {
+        self.set_key_server_versions(object_id, versions);
+        self
+    }
+
+    pub fn with_fault(self, object_id: ObjectID, fault: InMemoryFault) -> Self {
+        self.set_fault(object_id, Some(fault));
+        self
+    }
+
+    /// Wait `latency` before answering every lookup.
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+
+    /// Insert or replace a key server, keeping its fault if any.
+    pub fn set_key_server(&self, key_server: KeyServerInfo) {
+        self.set_key_server_versions(key_server.object_id, [key_server]);
+    }
+
+    pub fn set_key_server_versions(
+        &self,
+        object_id: ObjectID,
+        versions: impl IntoIterator<Item = KeyServerInfo>,
+    ) {
+        let mut key_servers = self.key_servers.lock().unwrap();
+        let entry = key_servers.entry(object_id).or_default();
+
+        entry.versions = versions.into_iter().collect();
+        entry.next_version = 0;
+    }
+
+    pub fn remove_key_server(&self, object_id: ObjectID) {
+        self.key_servers.lock().unwrap().remove(&object_id);
+    }
+
+    /// Set or clear the fault injected for `object_id`.
+    pub fn set_fault(&self, object_id: ObjectID, fault: Option<InMemoryFault>) {
+        self.key_servers
+            .lock()
+            .unwrap()
+            .entry(object_id)
+            .or_default()
+            .fault = fault;
+    }
+
+    /// Number of key server lookups served so far, batched ones included.
+    pub fn lookups(&self) -> usize {
+        self.lookups.load(Ordering::SeqCst)
+    }
+
+    fn next_key_server_info(
+        &self,
+        object_id: ObjectID,
+    ) -> Result<KeyServerInfo, InMemorySuiClientError> {
+        let mut key_servers = self.key_servers.lock().unwrap();
+        let entry = key_servers
+            .get_mut(&object_id)
+            .filter(|entry| !entry.versions.is_empty())
+            .ok_or(InMemorySuiClientError::KeyServerNotFound { object_id })?;
+
+        let info = entry.versions[entry.next_version % entry.versions.len()].clone();
+        entry.next_version = (entry.next_version + 1) % entry.versions.len();
+
+        Ok(info)
+    }
+}
+
+#[async_trait]
+impl SuiClient for InMemorySuiClient {
+    type Error = InMemorySuiClientError;
+
+    async fn get_key_server_info(
+        &self,
+        key_server_id: [u8; 32],
+    ) -> Result<KeyServerInfo, Self::Error> {
+        let object_id = ObjectID(key_server_id);
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+
+        if let Some(latency) = self.latency {
+            tokio::time::sleep(latency).await;
+        }
+
+        let fault = self
+            .key_servers
+            .lock()
+            .unwrap()
+            .get(&object_id)
+            .and_then(|entry| entry.fault.clone());
+
+        match fault {
+            Some(InMemoryFault::NotFound) => {
+                return Err(InMemorySuiClientError::KeyServerNotFound { object_id });
+            }
+            Some(InMemoryFault::Error(message)) => {
+                return Err(InMemorySuiClientError::Injected { object_id, message });
+            }
+            Some(InMemoryFault::Latency(latency)) => tokio::time::sleep(latency).await,
+            None => {}
+        }
+
+        self.next_key_server_info(object_id)
+    }
+}
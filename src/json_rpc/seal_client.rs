@@ -0,0 +1,66 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_client::{BaseSealClient, DerivedKeys, KeyServerInfo};
+use crate::cache::NoCache;
+use crate::cache_key::{DerivedKeyCacheKey, KeyServerInfoCacheKey};
+use crate::http_client::HttpClient;
+use crate::json_rpc::sui_client::JsonRpcSuiClient;
+use crate::sui_client::SuiClient;
+use reqwest::Client;
+
+/// [`BaseSealClient`] specialization that reads key servers through [`JsonRpcSuiClient`].
+///
+/// Equivalent to [`SealClient`](crate::native_sui_sdk::client::seal_client::SealClient)
+/// without the `sui_sdk` dependency: both the Sui JSON-RPC calls and the key server requests
+/// go through `reqwest::Client`. Build the crate with
+/// `default-features = false, features = ["client", "native-tls"]` to keep the Sui monorepo
+/// out of your dependency tree.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
+///
+/// let seal_client = JsonRpcSealClient::new("https://fullnode.testnet.sui.io:443");
+/// ```
+pub type JsonRpcSealClient = BaseSealClient<
+    NoCache<KeyServerInfoCacheKey, KeyServerInfo>,
+    NoCache<DerivedKeyCacheKey, DerivedKeys>,
+    <JsonRpcSuiClient<Client> as SuiClient>::Error,
+    JsonRpcSuiClient<Client>,
+    <Client as HttpClient>::PostError,
+    Client,
+>;
+
+impl JsonRpcSealClient {
+    pub fn new<S: Into<String>>(rpc_url: S) -> JsonRpcSealClient {
+        Self::new_with_http_client(rpc_url, Client::new())
+    }
+
+    /// Same as [`new`](Self::new), with a caller-configured `reqwest::Client`.
+    ///
+    /// The client is shared by the Sui JSON-RPC calls and the key server requests.
+    pub fn new_with_http_client<S: Into<String>>(
+        rpc_url: S,
+        http_client: Client,
+    ) -> JsonRpcSealClient {
+        BaseSealClient::new_custom(
+            ().into(),
+            ().into(),
+            JsonRpcSuiClient::new(http_client.clone(), rpc_url),
+            http_client,
+        )
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base_client::KeyServerInfo;
+use crate::generic_types::ObjectID;
+use crate::http_client::HttpClient;
+use crate::key_server_layout::{
+    KeyServerLayoutError, MAX_MULTI_GET_OBJECTS, ObjectRead, resolve_key_server,
+    resolve_key_servers,
+};
+use crate::sui_client::SuiClient;
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::fmt::Display;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum JsonRpcSuiClientError {
+    #[error("HTTP error while calling the Sui RPC: {message}")]
+    Http { message: String },
+
+    #[error("Sui RPC {url} answered with HTTP {status}: {response}")]
+    UnexpectedStatus {
+        url: String,
+        status: u16,
+        response: String,
+    },
+
+    #[error("JSON error: {0}")]
+    JSON(#[from] serde_json::Error),
+
+    #[error("Sui RPC error {code}: {message}")]
+    Rpc { code: i64, message: String },
+
+    #[error("No object data from the Sui RPC for object {object_id}")]
+    NoObjectDataFromTheSuiRPC { object_id: ObjectID },
+
+    #[error("Missing key server field: {field_name}")]
+    MissingKeyServerField { field_name: String },
+
+    #[error("Base64 decode error: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    #[error("Key server layout error: {0}")]
+    KeyServerLayout(#[from] KeyServerLayoutError),
+}
+
+/// [`SuiClient`] talking to a Sui fullnode's JSON-RPC API through any [`HttpClient`].
+///
+/// Unlike the `sui_sdk::SuiClient` adapter, this backend only needs the crate's own HTTP
+/// abstraction: it issues `sui_multiGetObjects` calls itself and decodes the BCS contents of
+/// the `KeyServer` dynamic fields with the layouts from
+/// [`key_server_layout`](crate::key_server_layout). Building the crate without the
+/// `native-sui-sdk` feature therefore avoids the Sui monorepo dependencies entirely.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use seal_sdk_rs::json_rpc::sui_client::JsonRpcSuiClient;
+///
+/// let sui_client = JsonRpcSuiClient::new(
+///     reqwest::Client::new(),
+///     "https://fullnode.testnet.sui.io:443",
+/// );
+/// ```
+#[derive(Clone)]
+pub struct JsonRpcSuiClient<Http> {
+    http_client: Http,
+    rpc_url: String,
+}
+
+impl<Http> JsonRpcSuiClient<Http> {
+    pub fn new<S: Into<String>>(http_client: Http, rpc_url: S) -> Self {
+        Self {
+            http_client,
+            rpc_url: rpc_url.into(),
+        }
+    }
+
+    pub fn rpc_url(&self) -> &str {
+        &self.rpc_url
+    }
+}
+
+#[async_trait]
+impl<Http> SuiClient for JsonRpcSuiClient<Http>
+where
+    Http: HttpClient + Send + Sync,
+    Http::PostError: Display,
+{
+    type Error = JsonRpcSuiClientError;
+
+    async fn get_key_server_info(
+        &self,
+        key_server_id: [u8; 32],
+    ) -> Result<KeyServerInfo, Self::Error> {
+        resolve_key_server(ObjectID(key_server_id), |object_ids| {
+            self.multi_get_objects(object_ids)
+        })
+        .await
+    }
+
+    async fn get_key_servers_info(
+        &self,
+        key_server_ids: Vec<[u8; 32]>,
+    ) -> Result<Vec<KeyServerInfo>, Self::Error> {
+        let key_server_ids = key_server_ids.into_iter().map(ObjectID).collect::<Vec<_>>();
+
+        resolve_key_servers(&key_server_ids, |object_ids| {
+            self.multi_get_objects(object_ids)
+        })
+        .await
+    }
+}
+
+impl<Http> JsonRpcSuiClient<Http>
+where
+    Http: HttpClient + Send + Sync,
+    Http::PostError: Display,
+{
+    /// Read the type and BCS contents of several objects, one `sui_multiGetObjects` call per
+    /// [`MAX_MULTI_GET_OBJECTS`] ids.
+    async fn multi_get_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> Result<Vec<ObjectRead>, JsonRpcSuiClientError> {
+        let mut objects = Vec::with_capacity(object_ids.len());
+        for chunk in object_ids.chunks(MAX_MULTI_GET_OBJECTS) {
+            let result = self
+                .call(
+                    "sui_multiGetObjects",
+                    json!([
+                        chunk.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                        { "showType": true, "showBcs": true },
+                    ]),
+                )
+                .await?;
+
+            for (object, object_id) in result
+                .as_array()
+                .ok_or_else(|| missing_field("result"))?
+                .iter()
+                .zip(chunk)
+            {
+                objects.push(object_read(*object_id, object)?);
+            }
+        }
+
+        Ok(objects)
+    }
+
+    async fn call(&self, method: &str, params: Value) -> Result<Value, JsonRpcSuiClientError> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let mut headers = HashMap::new();
+        headers.insert("Content-Type".to_string(), "application/json".to_string());
+
+        let response = self
+            .http_client
+            .post(&self.rpc_url, headers, body)
+            .await
+            .map_err(|err| JsonRpcSuiClientError::Http {
+                message: err.to_string(),
+            })?;
+
+        if !response.is_success() {
+            return Err(JsonRpcSuiClientError::UnexpectedStatus {
+                url: self.rpc_url.clone(),
+                status: response.status,
+                response: response.text,
+            });
+        }
+
+        let mut response: Value = serde_json::from_str(&response.text)?;
+
+        if let Some(error) = response.get("error") {
+            return Err(JsonRpcSuiClientError::Rpc {
+                code: error
+                    .get("code")
+                    .and_then(Value::as_i64)
+                    .unwrap_or_default(),
+                message: error
+                    .get("message")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+            });
+        }
+
+        response
+            .get_mut("result")
+            .map(Value::take)
+            .ok_or_else(|| missing_field("result"))
+    }
+}
+
+/// Convert one entry of a `sui_multiGetObjects` result.
+fn object_read(object_id: ObjectID, object: &Value) -> Result<ObjectRead, JsonRpcSuiClientError> {
+    // Missing objects are reported per entry rather than as an RPC error.
+    if let Some(error) = object.get("error") {
+        return Ok(match error.get("code").and_then(Value::as_str) {
+            Some("deleted") => ObjectRead::Deleted,
+            _ => ObjectRead::NotFound,
+        });
+    }
+
+    let data = object
+        .get("data")
+        .filter(|data| !data.is_null())
+        .ok_or(JsonRpcSuiClientError::NoObjectDataFromTheSuiRPC { object_id })?;
+
+    let object_type = data
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| missing_field("data.type"))?
+        .to_string();
+
+    // Packages have no Move contents; their type alone rules them out as key servers.
+    let contents = match data.get("bcs").and_then(|bcs| bcs.get("bcsBytes")) {
+        Some(bcs_bytes) => STANDARD.decode(
+            bcs_bytes
+                .as_str()
+                .ok_or_else(|| missing_field("data.bcs.bcsBytes"))?,
+        )?,
+        None => Vec::new(),
+    };
+
+    Ok(ObjectRead::Found {
+        object_type,
+        contents,
+    })
+}
+
+fn missing_field(field_name: &str) -> JsonRpcSuiClientError {
+    JsonRpcSuiClientError::MissingKeyServerField {
+        field_name: field_name.to_string(),
+    }
+}
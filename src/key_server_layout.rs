@@ -0,0 +1,431 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rust mirrors of the `KeyServer` Move structs stored by the Seal package.
+//!
+//! Every `KeyServer` object keeps its metadata in a dynamic field keyed by a `u64` version:
+//! `1` holds a [`KeyServerV1`] and `2` holds a [`KeyServerV2`]. Every backend fetches the raw
+//! BCS bytes of those values and decodes them here instead of walking JSON renderings, so the
+//! parsing follows the on-chain layout exactly.
+//!
+//! Supporting a new layout means adding its mirror struct, implementing [`KeyServerLayout`]
+//! for it, and registering its version in [`KEY_SERVER_VERSIONS`] and [`decode_key_server`].
+//!
+//! [`resolve_key_servers`] drives the lookups for every backend: it checks that each id points
+//! to a live `key_server::KeyServer` object and decodes its newest available version.
+
+use crate::base_client::{KeyServerInfo, PartialKeyServer, ServerType};
+use crate::generic_types::ObjectID;
+use fastcrypto::hash::{Blake2b256, HashFunction};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use thiserror::Error;
+
+/// Dynamic field name of the [`KeyServerV1`] value.
+pub const KEY_SERVER_V1: u64 = 1;
+
+/// Dynamic field name of the [`KeyServerV2`] value.
+pub const KEY_SERVER_V2: u64 = 2;
+
+/// Known key server versions, newest first. Backends try them in this order.
+pub const KEY_SERVER_VERSIONS: [u64; 2] = [KEY_SERVER_V2, KEY_SERVER_V1];
+
+/// Largest number of objects a fullnode accepts in one `sui_multiGetObjects` call. Backends
+/// split larger lookups into several calls.
+pub const MAX_MULTI_GET_OBJECTS: usize = 50;
+
+/// Module and struct name of the Seal key server object type.
+const KEY_SERVER_MODULE: &str = "key_server";
+const KEY_SERVER_STRUCT: &str = "KeyServer";
+
+/// Hashing domain separator Sui uses when deriving child object ids.
+const CHILD_OBJECT_ID_SCOPE: u8 = 0xf0;
+
+/// BCS encoding of `TypeTag::U64`, the type of the key server dynamic field names.
+const U64_TYPE_TAG_BCS: u8 = 0x02;
+
+#[derive(Debug, Error)]
+pub enum KeyServerLayoutError {
+    #[error("Unsupported key server version {version}")]
+    UnsupportedVersion { version: u64 },
+
+    #[error("Expected the dynamic field of key server version {expected}, got {found}")]
+    UnexpectedFieldName { expected: u64, found: u64 },
+
+    #[error("The batch lookup returned no result for key server {object_id}")]
+    MissingBatchResult { object_id: ObjectID },
+
+    #[error("Key server object {object_id} does not exist")]
+    KeyServerNotFound { object_id: ObjectID },
+
+    #[error("Key server object {object_id} has been deleted")]
+    KeyServerDeleted { object_id: ObjectID },
+
+    #[error("Object {object_id} is a {object_type}, not a Seal key_server::KeyServer")]
+    WrongKeyServerType {
+        object_id: ObjectID,
+        object_type: String,
+    },
+
+    #[error("Key server object {object_id} has no supported version dynamic field")]
+    NoKeyServerVersion { object_id: ObjectID },
+
+    #[error("BCS error: {0}")]
+    BCS(#[from] bcs::Error),
+}
+
+/// A versioned `KeyServer` value layout.
+pub trait KeyServerLayout: DeserializeOwned {
+    /// Dynamic field name under which the value is stored.
+    const VERSION: u64;
+
+    fn into_key_server_info(self, object_id: ObjectID, package_id: ObjectID) -> KeyServerInfo;
+
+    /// Decode the BCS bytes of the value.
+    fn from_bcs(bytes: &[u8]) -> Result<Self, KeyServerLayoutError> {
+        Ok(bcs::from_bytes(bytes)?)
+    }
+
+    /// Decode the BCS contents of the `Field<u64, Self>` object holding the value.
+    fn from_field_bcs(bytes: &[u8]) -> Result<Self, KeyServerLayoutError> {
+        let field = bcs::from_bytes::<DynamicField<Self>>(bytes)?;
+
+        if field.name != Self::VERSION {
+            return Err(KeyServerLayoutError::UnexpectedFieldName {
+                expected: Self::VERSION,
+                found: field.name,
+            });
+        }
+
+        Ok(field.value)
+    }
+}
+
+/// Layout of `sui::dynamic_field::Field<u64, V>`, the object wrapping each key server version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DynamicField<V> {
+    pub id: [u8; 32],
+    pub name: u64,
+    pub value: V,
+}
+
+/// Layout of `seal::key_server::KeyServerV1`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyServerV1 {
+    pub name: String,
+    pub url: String,
+    pub key_type: u8,
+    pub pk: Vec<u8>,
+}
+
+/// Layout of `seal::key_server::KeyServerV2`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyServerV2 {
+    pub name: String,
+    pub key_type: u8,
+    pub pk: Vec<u8>,
+    pub server_type: MoveServerType,
+    pub extra_fields: MoveBag,
+}
+
+/// Layout of `sui::bag::Bag`. Its entries live in dynamic fields, so only the `UID` and the entry
+/// count are stored inline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoveBag {
+    pub id: [u8; 32],
+    pub size: u64,
+}
+
+/// Layout of the `seal::key_server::ServerType` enum.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MoveServerType {
+    Independent {
+        url: String,
+    },
+    Committee {
+        version: u32,
+        threshold: u16,
+        partial_key_servers: Vec<MovePartialKeyServer>,
+    },
+}
+
+/// Layout of `seal::key_server::PartialKeyServer`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MovePartialKeyServer {
+    pub name: String,
+    pub url: String,
+    pub partial_pk: Vec<u8>,
+    pub party_id: u16,
+}
+
+impl KeyServerLayout for KeyServerV1 {
+    const VERSION: u64 = KEY_SERVER_V1;
+
+    fn into_key_server_info(self, object_id: ObjectID, package_id: ObjectID) -> KeyServerInfo {
+        KeyServerInfo {
+            object_id,
+            package_id,
+            name: self.name,
+            public_key: hex::encode(self.pk),
+            server_type: ServerType::Independent { url: self.url },
+        }
+    }
+}
+
+impl KeyServerLayout for KeyServerV2 {
+    const VERSION: u64 = KEY_SERVER_V2;
+
+    fn into_key_server_info(self, object_id: ObjectID, package_id: ObjectID) -> KeyServerInfo {
+        KeyServerInfo {
+            object_id,
+            package_id,
+            name: self.name,
+            public_key: hex::encode(self.pk),
+            server_type: self.server_type.into(),
+        }
+    }
+}
+
+impl From<MoveServerType> for ServerType {
+    fn from(value: MoveServerType) -> Self {
+        match value {
+            MoveServerType::Independent { url } => ServerType::Independent { url },
+            MoveServerType::Committee {
+                version,
+                threshold,
+                partial_key_servers,
+            } => ServerType::Committee {
+                version,
+                threshold,
+                partial_key_servers: partial_key_servers.into_iter().map(Into::into).collect(),
+            },
+        }
+    }
+}
+
+impl From<MovePartialKeyServer> for PartialKeyServer {
+    fn from(value: MovePartialKeyServer) -> Self {
+        PartialKeyServer {
+            name: value.name,
+            url: value.url,
+            partial_pk: value.partial_pk,
+            party_id: value.party_id,
+        }
+    }
+}
+
+/// Decode the BCS bytes of the key server value stored under `version`.
+pub fn decode_key_server(
+    object_id: ObjectID,
+    package_id: ObjectID,
+    version: u64,
+    bytes: &[u8],
+) -> Result<KeyServerInfo, KeyServerLayoutError> {
+    match version {
+        KEY_SERVER_V1 => {
+            Ok(KeyServerV1::from_bcs(bytes)?.into_key_server_info(object_id, package_id))
+        }
+        KEY_SERVER_V2 => {
+            Ok(KeyServerV2::from_bcs(bytes)?.into_key_server_info(object_id, package_id))
+        }
+        _ => Err(KeyServerLayoutError::UnsupportedVersion { version }),
+    }
+}
+
+/// Decode the BCS contents of the `Field<u64, _>` object holding the key server value stored
+/// under `version`.
+pub fn decode_key_server_field(
+    object_id: ObjectID,
+    package_id: ObjectID,
+    version: u64,
+    bytes: &[u8],
+) -> Result<KeyServerInfo, KeyServerLayoutError> {
+    match version {
+        KEY_SERVER_V1 => {
+            Ok(KeyServerV1::from_field_bcs(bytes)?.into_key_server_info(object_id, package_id))
+        }
+        KEY_SERVER_V2 => {
+            Ok(KeyServerV2::from_field_bcs(bytes)?.into_key_server_info(object_id, package_id))
+        }
+        _ => Err(KeyServerLayoutError::UnsupportedVersion { version }),
+    }
+}
+
+/// Outcome of reading one object by id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectRead {
+    /// The object exists. `contents` holds the BCS bytes of its Move struct.
+    Found {
+        object_type: String,
+        contents: Vec<u8>,
+    },
+    NotFound,
+    Deleted,
+}
+
+/// Package id of a `<package>::key_server::KeyServer` object, validating that `read` is one.
+pub fn key_server_package_id(
+    object_id: ObjectID,
+    read: &ObjectRead,
+) -> Result<ObjectID, KeyServerLayoutError> {
+    let object_type = match read {
+        ObjectRead::Found { object_type, .. } => object_type,
+        ObjectRead::NotFound => return Err(KeyServerLayoutError::KeyServerNotFound { object_id }),
+        ObjectRead::Deleted => return Err(KeyServerLayoutError::KeyServerDeleted { object_id }),
+    };
+
+    let wrong_type = || KeyServerLayoutError::WrongKeyServerType {
+        object_id,
+        object_type: object_type.clone(),
+    };
+
+    let [package, KEY_SERVER_MODULE, KEY_SERVER_STRUCT] =
+        object_type.split("::").collect::<Vec<_>>()[..]
+    else {
+        return Err(wrong_type());
+    };
+
+    // Type strings may use the short address form (leading zeros trimmed).
+    let package = package.strip_prefix("0x").unwrap_or(package);
+    if package.is_empty() || package.len() > 64 {
+        return Err(wrong_type());
+    }
+
+    let mut package_id = [0u8; 32];
+    hex::decode_to_slice(format!("{package:0>64}"), &mut package_id).map_err(|_| wrong_type())?;
+
+    Ok(ObjectID(package_id))
+}
+
+/// Resolve a single key server. See [`resolve_key_servers`].
+pub async fn resolve_key_server<Error, Fetch, Fut>(
+    key_server_id: ObjectID,
+    fetch_objects: Fetch,
+) -> Result<KeyServerInfo, Error>
+where
+    Error: From<KeyServerLayoutError>,
+    Fetch: FnMut(Vec<ObjectID>) -> Fut,
+    Fut: Future<Output = Result<Vec<ObjectRead>, Error>>,
+{
+    resolve_key_servers(&[key_server_id], fetch_objects)
+        .await?
+        .pop()
+        .ok_or_else(|| {
+            KeyServerLayoutError::MissingBatchResult {
+                object_id: key_server_id,
+            }
+            .into()
+        })
+}
+
+/// Resolve several key servers with one multi-get per known version.
+///
+/// `fetch_objects` receives object ids and returns one [`ObjectRead`] per id, in the same order.
+/// The first call reads the key server objects themselves, to check their type, along with the
+/// dynamic fields of the newest version. Servers lacking that version are retried with the next
+/// older one, so `N` servers cost at most one call per entry of [`KEY_SERVER_VERSIONS`]. Results
+/// follow the order of `key_server_ids`.
+pub async fn resolve_key_servers<Error, Fetch, Fut>(
+    key_server_ids: &[ObjectID],
+    mut fetch_objects: Fetch,
+) -> Result<Vec<KeyServerInfo>, Error>
+where
+    Error: From<KeyServerLayoutError>,
+    Fetch: FnMut(Vec<ObjectID>) -> Fut,
+    Fut: Future<Output = Result<Vec<ObjectRead>, Error>>,
+{
+    let mut package_ids = Vec::with_capacity(key_server_ids.len());
+    let mut resolved: Vec<Option<KeyServerInfo>> = vec![None; key_server_ids.len()];
+    let mut pending = (0..key_server_ids.len()).collect::<Vec<_>>();
+
+    for (round, version) in KEY_SERVER_VERSIONS.into_iter().enumerate() {
+        if pending.is_empty() {
+            break;
+        }
+
+        let mut object_ids = pending
+            .iter()
+            .map(|&index| key_server_field_id(key_server_ids[index], version))
+            .collect::<Vec<_>>();
+        if round == 0 {
+            object_ids.extend_from_slice(key_server_ids);
+        }
+
+        let expected_reads = object_ids.len();
+        let mut reads = fetch_objects(object_ids).await?;
+        if reads.len() != expected_reads {
+            return Err(KeyServerLayoutError::MissingBatchResult {
+                object_id: key_server_ids[pending[0]],
+            }
+            .into());
+        }
+
+        if round == 0 {
+            for (&object_id, read) in key_server_ids.iter().zip(&reads[pending.len()..]) {
+                package_ids.push(key_server_package_id(object_id, read)?);
+            }
+            reads.truncate(pending.len());
+        }
+
+        let mut missing = Vec::new();
+        for (index, read) in pending.into_iter().zip(reads) {
+            match read {
+                ObjectRead::Found { contents, .. } => {
+                    resolved[index] = Some(decode_key_server_field(
+                        key_server_ids[index],
+                        package_ids[index],
+                        version,
+                        &contents,
+                    )?);
+                }
+                ObjectRead::NotFound | ObjectRead::Deleted => {
+                    log::debug!(
+                        "seal: no V{} dynamic field for object_id={}",
+                        version,
+                        key_server_ids[index],
+                    );
+                    missing.push(index);
+                }
+            }
+        }
+        pending = missing;
+    }
+
+    if let Some(&index) = pending.first() {
+        return Err(KeyServerLayoutError::NoKeyServerVersion {
+            object_id: key_server_ids[index],
+        }
+        .into());
+    }
+
+    Ok(resolved.into_iter().flatten().collect())
+}
+
+/// Object id of the dynamic field holding version `version` of a key server.
+///
+/// Mirrors `sui::dynamic_field::hash_type_and_key` for a `u64` name, so backends that can only
+/// fetch objects by id do not need a dynamic field lookup round trip.
+pub fn key_server_field_id(key_server_id: ObjectID, version: u64) -> ObjectID {
+    let name = version.to_le_bytes();
+
+    let mut hasher = Blake2b256::default();
+    hasher.update([CHILD_OBJECT_ID_SCOPE]);
+    hasher.update(key_server_id.0);
+    hasher.update((name.len() as u64).to_le_bytes());
+    hasher.update(name);
+    hasher.update([U64_TYPE_TAG_BCS]);
+
+    ObjectID(hasher.finalize().digest)
+}
@@ -13,15 +13,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod approve_transaction;
 pub mod base_client;
+pub mod builder;
 pub mod cache;
 pub mod cache_key;
+pub mod clock;
 pub mod crypto;
 pub mod error;
+#[cfg(feature = "test-utils")]
+pub mod fault_injection;
 pub mod generic_types;
+#[cfg(feature = "graphql")]
+pub mod graphql;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 pub mod http_client;
+#[cfg(feature = "test-utils")]
+pub mod in_memory_sui_client;
+pub mod json_rpc;
+pub mod key_server_layout;
+#[cfg(feature = "test-utils")]
+pub mod mock_key_server;
+#[cfg(feature = "native-sui-sdk")]
 pub mod native_sui_sdk;
+#[cfg(feature = "test-utils")]
+pub mod record_replay;
+#[cfg(feature = "reqwest")]
 pub mod reqwest;
+pub mod retry;
+pub mod rng;
+pub mod seal_api;
 pub mod session_key;
 pub mod signer;
 pub mod sui_client;
@@ -0,0 +1,364 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! In-process mock of a Seal key server, for tests.
+//!
+//! [`MockKeyServer`] holds a freshly generated IBE master key and answers `/v1/fetch_key` and
+//! `/v1/service` requests the way a real independent key server does, without any network.
+//! Mount one or several servers behind a [`MockHttpClient`] and pass it to
+//! [`SealClientBuilder::http_client`](crate::builder::SealClientBuilder::http_client) to run
+//! encrypt/decrypt round trips in plain `cargo test`.
+//!
+//! The mock checks which ids are requested by decoding the `seal_approve*` calls of the
+//! approval PTB, then applies the [`MockKeyPolicy`] configured for each id. It does not
+//! execute the PTB nor verify the request certificate.
+
+use crate::approve_transaction::{SealApproveCall, seal_approve_calls};
+use crate::base_client::{KeyServerInfo, ServerType};
+use crate::crypto::{DST_POP, DecryptionKey, ElGamalPublicKey, FetchKeyResponse, ServiceResponse};
+use crate::error::SealClientError;
+use crate::generic_types::ObjectID;
+use crate::http_client::{HttpClient, PostResponse};
+use async_trait::async_trait;
+use base64::Engine;
+use fastcrypto::groups::bls12381::{G1Element, G2Element, Scalar};
+use fastcrypto::groups::{GroupElement, HashToGroupElement};
+use fastcrypto::serde_helpers::ToFromByteArray;
+use seal_crypto::{create_full_id, elgamal, ibe};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use sui_sdk_types::{Argument, Command, Identifier, Input, MoveCall, ProgrammableTransaction};
+
+/// What a [`MockKeyServer`] answers when asked for the key of an id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockKeyPolicy {
+    /// Return the derived key.
+    Allow,
+    /// Answer `403 Forbidden`, as a key server does when `seal_approve` aborts.
+    Deny,
+    /// Answer `503 Service Unavailable`.
+    Fail,
+}
+
+/// Pure-Rust key server serving ElGamal-encrypted user secret keys.
+pub struct MockKeyServer {
+    object_id: ObjectID,
+    package_id: ObjectID,
+    name: String,
+    url: String,
+    master_key: Scalar,
+    public_key: G2Element,
+    default_policy: MockKeyPolicy,
+    policies: HashMap<Vec<u8>, MockKeyPolicy>,
+    original_package_ids: HashMap<ObjectID, ObjectID>,
+    fetch_key_requests: AtomicUsize,
+}
+
+impl MockKeyServer {
+    /// Create a key server with a random master key, reachable at `url`.
+    pub fn new(object_id: ObjectID, url: impl Into<String>) -> Self {
+        let (master_key, _) = ibe::generate_key_pair(&mut rand::thread_rng());
+
+        Self::with_master_key(object_id, url, master_key)
+    }
+
+    /// Create a key server with a fixed master key, for reproducible fixtures.
+    pub fn with_master_key(
+        object_id: ObjectID,
+        url: impl Into<String>,
+        master_key: Scalar,
+    ) -> Self {
+        let url = url.into();
+
+        MockKeyServer {
+            object_id,
+            package_id: ObjectID([0; 32]),
+            name: format!("mock-{}", object_id),
+            url: url.trim_end_matches('/').to_string(),
+            master_key,
+            public_key: G2Element::generator() * master_key,
+            default_policy: MockKeyPolicy::Allow,
+            policies: HashMap::new(),
+            original_package_ids: HashMap::new(),
+            fetch_key_requests: AtomicUsize::new(0),
+        }
+    }
+
+    /// Set the package reported in [`key_server_info`](Self::key_server_info).
+    pub fn with_package_id(mut self, package_id: ObjectID) -> Self {
+        self.package_id = package_id;
+        self
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Policy applied to ids without a specific policy. Defaults to [`MockKeyPolicy::Allow`].
+    pub fn with_default_policy(mut self, policy: MockKeyPolicy) -> Self {
+        self.default_policy = policy;
+        self
+    }
+
+    /// Policy applied to `id`, the identity without its package prefix.
+    pub fn with_policy(mut self, id: impl Into<Vec<u8>>, policy: MockKeyPolicy) -> Self {
+        self.policies.insert(id.into(), policy);
+        self
+    }
+
+    /// Treat `upgraded_package_id` as an upgrade of `original_package_id`.
+    ///
+    /// Like real key servers, keys requested through a `seal_approve*` call into an upgrade are
+    /// derived from the original package id.
+    pub fn with_package_upgrade(
+        mut self,
+        original_package_id: ObjectID,
+        upgraded_package_id: ObjectID,
+    ) -> Self {
+        self.original_package_ids
+            .insert(upgraded_package_id, original_package_id);
+        self
+    }
+
+    pub fn object_id(&self) -> ObjectID {
+        self.object_id
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn public_key(&self) -> &G2Element {
+        &self.public_key
+    }
+
+    /// On-chain description of this server, as a [`SuiClient`](crate::sui_client::SuiClient)
+    /// would return it.
+    pub fn key_server_info(&self) -> KeyServerInfo {
+        KeyServerInfo {
+            object_id: self.object_id,
+            package_id: self.package_id,
+            name: self.name.clone(),
+            public_key: hex::encode(self.public_key.to_byte_array()),
+            server_type: ServerType::Independent {
+                url: self.url.clone(),
+            },
+        }
+    }
+
+    /// Number of `/v1/fetch_key` requests received so far, including rejected ones.
+    pub fn fetch_key_requests(&self) -> usize {
+        self.fetch_key_requests.load(Ordering::SeqCst)
+    }
+
+    /// Answer a `/v1/fetch_key` request body.
+    pub fn fetch_key(&self, body: &str) -> PostResponse {
+        self.fetch_key_requests.fetch_add(1, Ordering::SeqCst);
+
+        let request: MockFetchKeyRequest = match serde_json::from_str(body) {
+            Ok(request) => request,
+            Err(err) => return error_response(400, "InvalidRequest", err),
+        };
+
+        let ptb = match base64::engine::general_purpose::STANDARD.decode(&request.ptb) {
+            Ok(ptb) => ptb,
+            Err(err) => return error_response(400, "InvalidPTB", err),
+        };
+
+        let calls = match seal_approve_calls(&ptb) {
+            Ok(calls) => calls,
+            Err(err) => return error_response(400, "InvalidPTB", err),
+        };
+
+        for SealApproveCall { id, .. } in calls.iter() {
+            match self
+                .policies
+                .get(id)
+                .copied()
+                .unwrap_or(self.default_policy)
+            {
+                MockKeyPolicy::Allow => {}
+                MockKeyPolicy::Deny => {
+                    return error_response(403, "NoAccess", hex::encode(id));
+                }
+                MockKeyPolicy::Fail => {
+                    return error_response(503, "Failure", hex::encode(id));
+                }
+            }
+        }
+
+        let mut rng = rand::thread_rng();
+        let decryption_keys = calls
+            .into_iter()
+            .map(|call| {
+                let package_id = self
+                    .original_package_ids
+                    .get(&call.package_id)
+                    .copied()
+                    .unwrap_or(call.package_id);
+                let full_id = create_full_id(&package_id.0, &call.id);
+                let user_secret_key = ibe::extract(&self.master_key, &full_id);
+                let encrypted_key =
+                    elgamal::encrypt(&mut rng, &user_secret_key, &request.enc_key).into();
+
+                DecryptionKey {
+                    id: full_id,
+                    encrypted_key,
+                }
+            })
+            .collect();
+
+        match serde_json::to_string(&FetchKeyResponse { decryption_keys }) {
+            Ok(text) => PostResponse::new(200, text),
+            Err(err) => error_response(500, "Failure", err),
+        }
+    }
+
+    /// Answer a `/v1/service` request with a proof of possession of the master key.
+    pub fn service(&self) -> PostResponse {
+        let mut message = DST_POP.to_vec();
+        message.extend(self.public_key.to_byte_array());
+        message.extend(self.object_id.0);
+
+        let response = ServiceResponse {
+            service_id: self.object_id,
+            pop: G1Element::hash_to_group_element(&message) * self.master_key,
+        };
+
+        match serde_json::to_string(&response) {
+            Ok(text) => PostResponse::new(200, text),
+            Err(err) => error_response(500, "Failure", err),
+        }
+    }
+}
+
+/// [`HttpClient`] routing requests to in-process [`MockKeyServer`]s by URL.
+///
+/// Requests to URLs not served by any mounted server answer `404 Not Found`.
+#[derive(Clone, Default)]
+pub struct MockHttpClient {
+    servers: Vec<Arc<MockKeyServer>>,
+}
+
+impl MockHttpClient {
+    pub fn new(servers: impl IntoIterator<Item = Arc<MockKeyServer>>) -> Self {
+        MockHttpClient {
+            servers: servers.into_iter().collect(),
+        }
+    }
+
+    pub fn with_server(mut self, server: Arc<MockKeyServer>) -> Self {
+        self.servers.push(server);
+        self
+    }
+
+    pub fn servers(&self) -> &[Arc<MockKeyServer>] {
+        &self.servers
+    }
+
+    fn route<'a>(&self, url: &'a str) -> Option<(&MockKeyServer, &'a str)> {
+        self.servers.iter().find_map(|server| {
+            url.strip_prefix(server.url.as_str())
+                .filter(|path| path.starts_with('/'))
+                .map(|path| (server.as_ref(), path))
+        })
+    }
+}
+
+#[async_trait]
+impl HttpClient for MockHttpClient {
+    type PostError = Infallible;
+
+    async fn post<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<PostResponse, Self::PostError> {
+        let response = match self.route(url) {
+            Some((server, "/v1/fetch_key")) => server.fetch_key(&body.to_string()),
+            Some(_) | None => PostResponse::new(404, "Not Found"),
+        };
+
+        Ok(response)
+    }
+
+    async fn get(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+    ) -> Result<PostResponse, Self::PostError> {
+        let response = match self.route(url) {
+            Some((server, path)) if path.starts_with("/v1/service") => server.service(),
+            Some(_) | None => PostResponse::new(404, "Not Found"),
+        };
+
+        Ok(response)
+    }
+}
+
+/// Approval PTB calling `{package_id}::{module}::seal_approve(id)` once per id.
+///
+/// The mock only looks at the ids, so this is enough for policies that take no other argument.
+pub fn seal_approve_transaction(
+    package_id: ObjectID,
+    module: &str,
+    ids: &[Vec<u8>],
+) -> Result<ProgrammableTransaction, SealClientError> {
+    let module = Identifier::new(module).map_err(|err| anyhow::anyhow!("{err}"))?;
+    let function = Identifier::new("seal_approve").map_err(|err| anyhow::anyhow!("{err}"))?;
+
+    let inputs = ids
+        .iter()
+        .map(|id| {
+            Ok(Input::Pure {
+                value: bcs::to_bytes(id)?,
+            })
+        })
+        .collect::<Result<Vec<_>, SealClientError>>()?;
+
+    let commands = (0..ids.len())
+        .map(|index| {
+            Command::MoveCall(MoveCall {
+                package: package_id.into(),
+                module: module.clone(),
+                function: function.clone(),
+                type_arguments: vec![],
+                arguments: vec![Argument::Input(index as u16)],
+            })
+        })
+        .collect();
+
+    Ok(ProgrammableTransaction { inputs, commands })
+}
+
+/// Fields of a fetch key request the mock needs.
+#[derive(Deserialize)]
+struct MockFetchKeyRequest {
+    ptb: String,
+    enc_key: ElGamalPublicKey,
+}
+
+fn error_response(status: u16, error: &str, message: impl ToString) -> PostResponse {
+    let body = serde_json::json!({
+        "error": error,
+        "message": message.to_string(),
+    });
+
+    PostResponse::new(status, body.to_string())
+}
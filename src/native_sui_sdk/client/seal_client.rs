@@ -95,7 +95,38 @@ pub type SealClient = BaseSealClient<
 
 impl SealClient {
     pub fn new(sui_client: sui_sdk::SuiClient) -> SealClient {
-        BaseSealClient::new_custom(().into(), ().into(), sui_client, Client::new())
+        Self::new_with_http_client(sui_client, Client::new())
+    }
+
+    /// Same as [`new`](Self::new), but sends key server requests through `http_client`.
+    ///
+    /// Use it to set proxies, timeouts, custom root certificates or connection pool limits
+    /// without spelling out the generic parameters of [`BaseSealClient::new_custom`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use seal_sdk_rs::native_sui_sdk::client::seal_client::SealClient;
+    /// use std::time::Duration;
+    /// use sui_sdk::SuiClientBuilder;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let sui_client = SuiClientBuilder::default()
+    ///         .build("https://fullnode.testnet.sui.io:443")
+    ///         .await?;
+    ///
+    ///     let http_client = reqwest::Client::builder()
+    ///         .timeout(Duration::from_secs(10))
+    ///         .pool_max_idle_per_host(4)
+    ///         .build()?;
+    ///
+    ///     let seal_client = SealClient::new_with_http_client(sui_client, http_client);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new_with_http_client(sui_client: sui_sdk::SuiClient, http_client: Client) -> SealClient {
+        BaseSealClient::new_custom(().into(), ().into(), sui_client, http_client)
     }
 }
 
@@ -157,11 +188,19 @@ pub type SealClientLeakingCache = BaseSealClient<
 
 impl SealClientLeakingCache {
     pub fn new(sui_client: sui_sdk::SuiClient) -> SealClientLeakingCache {
+        Self::new_with_http_client(sui_client, Client::new())
+    }
+
+    /// Same as [`new`](Self::new), with a caller-configured `reqwest::Client`.
+    pub fn new_with_http_client(
+        sui_client: sui_sdk::SuiClient,
+        http_client: Client,
+    ) -> SealClientLeakingCache {
         BaseSealClient::new_custom(
             Default::default(),
             Default::default(),
             sui_client,
-            Client::new(),
+            http_client,
         )
     }
 }
@@ -250,12 +289,35 @@ pub mod moka {
                 DerivedKeys,
                 Cache<DerivedKeyCacheKey, DerivedKeys>,
             >,
+        ) -> SealClientMokaCache {
+            Self::new_with_http_client(
+                sui_client,
+                key_server_cache_builder,
+                derived_keys_cache_builder,
+                Client::new(),
+            )
+        }
+
+        /// Same as [`new`](Self::new), with a caller-configured `reqwest::Client`.
+        pub fn new_with_http_client(
+            sui_client: sui_sdk::SuiClient,
+            key_server_cache_builder: CacheBuilder<
+                KeyServerInfoCacheKey,
+                KeyServerInfo,
+                Cache<KeyServerInfoCacheKey, KeyServerInfo>,
+            >,
+            derived_keys_cache_builder: CacheBuilder<
+                DerivedKeyCacheKey,
+                DerivedKeys,
+                Cache<DerivedKeyCacheKey, DerivedKeys>,
+            >,
+            http_client: Client,
         ) -> SealClientMokaCache {
             BaseSealClient::new_custom(
                 key_server_cache_builder.build(),
                 derived_keys_cache_builder.build(),
                 sui_client,
-                Client::new(),
+                http_client,
             )
         }
     }
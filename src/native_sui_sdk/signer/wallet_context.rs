@@ -20,7 +20,8 @@ use fastcrypto::traits::ToFromBytes;
 use shared_crypto::intent::Intent;
 use sui_keys::key_identity::KeyIdentity;
 use sui_keys::keystore::{AccountKeystore, Keystore};
-use sui_types::crypto::{Signature, SuiSignature};
+use sui_sdk::wallet_context::WalletContext;
+use sui_types::crypto::{Signature, SignatureScheme, SuiSignature};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -39,46 +40,139 @@ pub enum WalletContextError {
 }
 
 #[async_trait]
-impl Signer for sui_sdk::wallet_context::WalletContext {
+impl Signer for WalletContext {
     type Error = WalletContextError;
     async fn sign_personal_message(
         &mut self,
         message: Vec<u8>,
     ) -> Result<Ed25519Signature, WalletContextError> {
-        let generic_address = self.get_sui_address()?;
-        let address = generic_address.into();
-        let identity = KeyIdentity::Address(address);
-        let keystore = self.get_keystore_by_identity(&identity)?;
-
-        let signature = keystore
-            .sign_secure(&address, &message, Intent::personal_message())
-            .await
-            .map_err(|err| WalletContextError::SignatureError {
-                message: err.to_string(),
-            })?;
-
-        let Signature::Ed25519SuiSignature(signature) = signature else {
-            return Err(WalletContextError::IncorrectSignatureScheme);
-        };
-
-        Ok(Ed25519Signature::from_bytes(signature.signature_bytes())?)
+        let address = self.get_sui_address()?;
+
+        sign_personal_message_for_address(self, address.into(), message).await
     }
 
     fn get_public_key(&mut self) -> Result<Ed25519PublicKey, WalletContextError> {
-        let generic_address = self.get_sui_address()?;
-        let address = generic_address.into();
-        let identity = KeyIdentity::Address(address);
-        let keystore = self.get_keystore_by_identity(&identity)?;
-        let public_key = match keystore {
-            Keystore::File(file_keystore) => file_keystore.export(&address)?.public(),
-            Keystore::InMem(in_mem_keystore) => in_mem_keystore.export(&address)?.public(),
-            Keystore::External(external_keystore) => external_keystore.export(&address)?.public(),
-        };
-
-        Ok(Ed25519PublicKey::from_bytes(public_key.as_ref())?)
+        let address = self.get_sui_address()?;
+
+        public_key_for_address(self, address.into())
     }
 
     fn get_sui_address(&mut self) -> Result<SuiAddress, WalletContextError> {
         Ok(SuiAddress(self.active_address()?.to_inner()))
     }
 }
+
+/// [`Signer`] bound to an explicit address of a `WalletContext` keystore.
+///
+/// The `WalletContext` implementation of [`Signer`] always signs with the active address.
+/// This wrapper borrows the wallet immutably and signs for the address it was created with,
+/// so several session keys for different addresses of the same keystore can be minted
+/// concurrently without switching the active address.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use seal_sdk_rs::generic_types::{ObjectID, SuiAddress};
+/// use seal_sdk_rs::native_sui_sdk::signer::wallet_context::WalletContextSigner;
+/// use seal_sdk_rs::session_key::SessionKey;
+/// use sui_sdk::wallet_context::WalletContext;
+///
+/// # async fn demo(
+/// #     wallet: &WalletContext,
+/// #     package_id: ObjectID,
+/// #     alice: SuiAddress,
+/// #     bob: SuiAddress,
+/// # ) -> anyhow::Result<()> {
+/// let mut alice_signer = WalletContextSigner::new(wallet, alice);
+/// let mut bob_signer = WalletContextSigner::new(wallet, bob);
+///
+/// let (alice_session_key, bob_session_key) = futures::try_join!(
+///     SessionKey::new(package_id, None, 5, &mut alice_signer),
+///     SessionKey::new(package_id, None, 5, &mut bob_signer),
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct WalletContextSigner<'a> {
+    wallet: &'a WalletContext,
+    address: SuiAddress,
+}
+
+impl<'a> WalletContextSigner<'a> {
+    pub fn new<A>(wallet: &'a WalletContext, address: A) -> Self
+    where
+        SuiAddress: From<A>,
+    {
+        Self {
+            wallet,
+            address: address.into(),
+        }
+    }
+
+    pub fn address(&self) -> SuiAddress {
+        self.address
+    }
+}
+
+#[async_trait]
+impl Signer for WalletContextSigner<'_> {
+    type Error = WalletContextError;
+
+    async fn sign_personal_message(
+        &mut self,
+        message: Vec<u8>,
+    ) -> Result<Ed25519Signature, WalletContextError> {
+        sign_personal_message_for_address(self.wallet, self.address.into(), message).await
+    }
+
+    fn get_public_key(&mut self) -> Result<Ed25519PublicKey, WalletContextError> {
+        public_key_for_address(self.wallet, self.address.into())
+    }
+
+    fn get_sui_address(&mut self) -> Result<SuiAddress, WalletContextError> {
+        Ok(self.address)
+    }
+}
+
+async fn sign_personal_message_for_address(
+    wallet: &WalletContext,
+    address: sui_types::base_types::SuiAddress,
+    message: Vec<u8>,
+) -> Result<Ed25519Signature, WalletContextError> {
+    let identity = KeyIdentity::Address(address);
+    let keystore = wallet.get_keystore_by_identity(&identity)?;
+
+    let signature = keystore
+        .sign_secure(&address, &message, Intent::personal_message())
+        .await
+        .map_err(|err| WalletContextError::SignatureError {
+            message: err.to_string(),
+        })?;
+
+    let Signature::Ed25519SuiSignature(signature) = signature else {
+        return Err(WalletContextError::IncorrectSignatureScheme);
+    };
+
+    Ok(Ed25519Signature::from_bytes(signature.signature_bytes())?)
+}
+
+fn public_key_for_address(
+    wallet: &WalletContext,
+    address: sui_types::base_types::SuiAddress,
+) -> Result<Ed25519PublicKey, WalletContextError> {
+    let identity = KeyIdentity::Address(address);
+    let keystore = wallet.get_keystore_by_identity(&identity)?;
+    let public_key = match keystore {
+        Keystore::File(file_keystore) => file_keystore.export(&address)?.public(),
+        Keystore::InMem(in_mem_keystore) => in_mem_keystore.export(&address)?.public(),
+        Keystore::External(external_keystore) => external_keystore.export(&address)?.public(),
+    };
+
+    // Only Ed25519 keys can back a session key certificate, reject other schemes up front
+    // instead of failing on a byte-length mismatch.
+    if public_key.scheme() != SignatureScheme::ED25519 {
+        return Err(WalletContextError::IncorrectSignatureScheme);
+    }
+
+    Ok(Ed25519PublicKey::from_bytes(public_key.as_ref())?)
+}
@@ -0,0 +1,312 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Record and replay key server traffic, for deterministic regression tests.
+//!
+//! [`RecordingHttpClient`] wraps a real [`HttpClient`] and captures every request/response
+//! pair into an [`HttpTrace`], which can be saved as JSON and attached to a bug report.
+//! [`ReplayHttpClient`] serves a trace back without any network.
+//!
+//! Key server responses are encrypted to the ephemeral ElGamal key of the request, so a replay
+//! only decrypts if the client draws the same key: record and replay with the same session key
+//! and a client seeded through
+//! [`BaseSealClient::with_rng`](crate::base_client::BaseSealClient::with_rng).
+
+use crate::http_client::{HttpClient, HttpResponse, PostResponse};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::Mutex;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ReplayError {
+    #[error("No recorded {method} exchange left for {url}")]
+    NoRecordedExchange { method: String, url: String },
+
+    #[error("The {method} request to {url} does not match the recorded body")]
+    RequestBodyMismatch { method: String, url: String },
+}
+
+#[derive(Debug, Error)]
+pub enum HttpTraceError {
+    #[error("JSON error: {0}")]
+    JSON(#[from] serde_json::Error),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// One request and the response it received.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedExchange {
+    pub method: String,
+    pub url: String,
+    /// Request body, `None` for GET requests.
+    pub request_body: Option<String>,
+    pub status: u16,
+    pub response_body: String,
+    #[serde(default)]
+    pub response_headers: HashMap<String, String>,
+}
+
+impl RecordedExchange {
+    fn response(&self) -> HttpResponse {
+        HttpResponse {
+            status: self.status,
+            text: self.response_body.clone(),
+            headers: self.response_headers.clone(),
+        }
+    }
+}
+
+/// Ordered list of recorded exchanges, serialized as JSON.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpTrace {
+    pub exchanges: Vec<RecordedExchange>,
+}
+
+impl HttpTrace {
+    pub fn from_json(json: &str) -> Result<Self, HttpTraceError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn to_json(&self) -> Result<String, HttpTraceError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, HttpTraceError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), HttpTraceError> {
+        Ok(std::fs::write(path, self.to_json()?)?)
+    }
+}
+
+/// [`HttpClient`] forwarding requests to `inner` and recording the exchanges.
+///
+/// Requests failing in `inner`, e.g. with a transport error, are not recorded.
+pub struct RecordingHttpClient<H> {
+    inner: H,
+    trace: Mutex<HttpTrace>,
+}
+
+impl<H> RecordingHttpClient<H> {
+    pub fn new(inner: H) -> Self {
+        RecordingHttpClient {
+            inner,
+            trace: Mutex::new(HttpTrace::default()),
+        }
+    }
+
+    pub fn inner(&self) -> &H {
+        &self.inner
+    }
+
+    /// Exchanges recorded so far.
+    pub fn trace(&self) -> HttpTrace {
+        self.trace.lock().unwrap().clone()
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), HttpTraceError> {
+        self.trace().save(path)
+    }
+
+    fn record(
+        &self,
+        method: &str,
+        url: &str,
+        request_body: Option<String>,
+        response: &HttpResponse,
+    ) {
+        self.trace.lock().unwrap().exchanges.push(RecordedExchange {
+            method: method.to_string(),
+            url: url.to_string(),
+            request_body,
+            status: response.status,
+            response_body: response.text.clone(),
+            response_headers: response.headers.clone(),
+        });
+    }
+}
+
+#[async_trait]
+impl<H> HttpClient for RecordingHttpClient<H>
+where
+    H: HttpClient + Send,
+{
+    type PostError = H::PostError;
+
+    async fn post<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<PostResponse, Self::PostError> {
+        self.post_with_headers(url, headers, body)
+            .await
+            .map(Into::into)
+    }
+
+    async fn get(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<PostResponse, Self::PostError> {
+        self.get_with_headers(url, headers).await.map(Into::into)
+    }
+
+    async fn post_with_headers<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<HttpResponse, Self::PostError> {
+        let body = body.to_string();
+        let response = self
+            .inner
+            .post_with_headers(url, headers, body.clone())
+            .await?;
+        self.record("POST", url, Some(body), &response);
+
+        Ok(response)
+    }
+
+    async fn get_with_headers(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<HttpResponse, Self::PostError> {
+        let response = self.inner.get_with_headers(url, headers).await?;
+        self.record("GET", url, None, &response);
+
+        Ok(response)
+    }
+}
+
+/// [`HttpClient`] answering from an [`HttpTrace`].
+///
+/// Exchanges are matched by method and URL, in recording order, so concurrent requests to
+/// different key servers replay correctly. Request bodies are only compared when
+/// [`with_body_matching`](Self::with_body_matching) is enabled, since they embed the session
+/// key's certificate.
+pub struct ReplayHttpClient {
+    exchanges: Mutex<HashMap<(String, String), VecDeque<RecordedExchange>>>,
+    match_bodies: bool,
+}
+
+impl ReplayHttpClient {
+    pub fn new(trace: HttpTrace) -> Self {
+        let mut exchanges: HashMap<_, VecDeque<_>> = HashMap::new();
+        for exchange in trace.exchanges {
+            exchanges
+                .entry((exchange.method.clone(), exchange.url.clone()))
+                .or_default()
+                .push_back(exchange);
+        }
+
+        ReplayHttpClient {
+            exchanges: Mutex::new(exchanges),
+            match_bodies: false,
+        }
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, HttpTraceError> {
+        Ok(Self::new(HttpTrace::load(path)?))
+    }
+
+    /// Fail with [`ReplayError::RequestBodyMismatch`] when a request body differs from the
+    /// recorded one.
+    pub fn with_body_matching(mut self, enabled: bool) -> Self {
+        self.match_bodies = enabled;
+        self
+    }
+
+    /// Number of recorded exchanges not replayed yet.
+    pub fn remaining(&self) -> usize {
+        self.exchanges
+            .lock()
+            .unwrap()
+            .values()
+            .map(VecDeque::len)
+            .sum()
+    }
+
+    fn replay(
+        &self,
+        method: &str,
+        url: &str,
+        request_body: Option<String>,
+    ) -> Result<HttpResponse, ReplayError> {
+        let mut exchanges = self.exchanges.lock().unwrap();
+        let exchange = exchanges
+            .get_mut(&(method.to_string(), url.to_string()))
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| ReplayError::NoRecordedExchange {
+                method: method.to_string(),
+                url: url.to_string(),
+            })?;
+
+        if self.match_bodies && exchange.request_body != request_body {
+            return Err(ReplayError::RequestBodyMismatch {
+                method: method.to_string(),
+                url: url.to_string(),
+            });
+        }
+
+        Ok(exchange.response())
+    }
+}
+
+#[async_trait]
+impl HttpClient for ReplayHttpClient {
+    type PostError = ReplayError;
+
+    async fn post<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<PostResponse, Self::PostError> {
+        self.replay("POST", url, Some(body.to_string()))
+            .map(Into::into)
+    }
+
+    async fn get(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+    ) -> Result<PostResponse, Self::PostError> {
+        self.replay("GET", url, None).map(Into::into)
+    }
+
+    async fn post_with_headers<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<HttpResponse, Self::PostError> {
+        self.replay("POST", url, Some(body.to_string()))
+    }
+
+    async fn get_with_headers(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+    ) -> Result<HttpResponse, Self::PostError> {
+        self.replay("GET", url, None)
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::error::ReqwestError;
-use crate::http_client::{HttpClient, PostResponse};
+use crate::http_client::{HttpClient, HttpResponse, PostResponse};
 use async_trait::async_trait;
 use http::{HeaderMap, HeaderName, HeaderValue};
 use reqwest::Body;
@@ -30,23 +30,82 @@ impl HttpClient for reqwest::Client {
         headers: HashMap<String, String>,
         body: S,
     ) -> Result<PostResponse, Self::PostError> {
-        let mut header_map = HeaderMap::new();
+        HttpClient::post_with_headers(self, url, headers, body)
+            .await
+            .map(Into::into)
+    }
+
+    async fn get(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<PostResponse, Self::PostError> {
+        HttpClient::get_with_headers(self, url, headers)
+            .await
+            .map(Into::into)
+    }
 
-        for (key, value) in headers {
-            header_map.insert(HeaderName::from_str(&key)?, HeaderValue::from_str(&value)?);
-        }
+    async fn post_with_headers<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<HttpResponse, Self::PostError> {
         let response = self
             .post(url)
-            .headers(header_map)
+            .headers(header_map(headers)?)
             .body(Body::from(body.to_string()))
             .send()
             .await?;
 
-        let status = response.status().as_u16();
-        let text = response.text().await?;
+        into_http_response(response).await
+    }
 
-        let response = PostResponse { status, text };
+    async fn get_with_headers(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<HttpResponse, Self::PostError> {
+        let response = self.get(url).headers(header_map(headers)?).send().await?;
 
-        Ok(response)
+        into_http_response(response).await
     }
 }
+
+async fn into_http_response(response: reqwest::Response) -> Result<HttpResponse, ReqwestError> {
+    let status = response.status().as_u16();
+
+    let mut headers = HashMap::<String, String>::new();
+    for (name, value) in response.headers() {
+        // Headers that are not valid UTF-8 are not used by the SDK.
+        let Ok(value) = value.to_str() else {
+            continue;
+        };
+
+        headers
+            .entry(name.as_str().to_string())
+            .and_modify(|joined| {
+                joined.push_str(", ");
+                joined.push_str(value);
+            })
+            .or_insert_with(|| value.to_string());
+    }
+
+    let text = response.text().await?;
+
+    Ok(HttpResponse {
+        status,
+        text,
+        headers,
+    })
+}
+
+fn header_map(headers: HashMap<String, String>) -> Result<HeaderMap, ReqwestError> {
+    let mut header_map = HeaderMap::new();
+
+    for (key, value) in headers {
+        header_map.insert(HeaderName::from_str(&key)?, HeaderValue::from_str(&value)?);
+    }
+
+    Ok(header_map)
+}
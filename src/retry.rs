@@ -0,0 +1,81 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+/// How [`BaseSealClient`](crate::base_client::BaseSealClient) retries key server requests.
+///
+/// Transport errors, timeouts, `429 Too Many Requests` and `5xx` responses are retried up to
+/// `max_attempts` requests in total. The delay between attempts doubles from
+/// `initial_backoff` up to `max_backoff`, unless the server sends a `Retry-After` header, which
+/// is honored up to `max_backoff`.
+///
+/// The default performs a single attempt, i.e. no retries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Delay before retrying after the failed `attempt` (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+
+        self.initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff)
+    }
+
+    /// Delay requested by a `Retry-After` header in seconds, capped at `max_backoff`.
+    ///
+    /// HTTP dates are not supported and yield `None`.
+    pub fn retry_after(&self, header: &str) -> Option<Duration> {
+        header
+            .trim()
+            .parse::<u64>()
+            .ok()
+            .map(|seconds| Duration::from_secs(seconds).min(self.max_backoff))
+    }
+
+    /// Whether a response with `status` is worth retrying.
+    pub fn is_retryable_status(status: u16) -> bool {
+        status == 429 || (500..600).contains(&status)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
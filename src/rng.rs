@@ -0,0 +1,35 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Injectable randomness.
+//!
+//! The SDK draws from `rand::thread_rng()` unless a [`SealRng`] is supplied, e.g. a seeded
+//! `rand::rngs::StdRng` to make requests reproducible.
+
+use rand::{CryptoRng, RngCore};
+use std::sync::{Arc, Mutex};
+
+/// Cryptographically secure randomness source accepted by the SDK.
+///
+/// Implemented for every `RngCore + CryptoRng + Send` type.
+pub trait SealRng: RngCore + CryptoRng + Send {}
+
+impl<R: RngCore + CryptoRng + Send> SealRng for R {}
+
+/// [`SealRng`] shared by the clones of a client.
+pub type SharedRng = Arc<Mutex<dyn SealRng>>;
+
+pub fn shared_rng<R: SealRng + 'static>(rng: R) -> SharedRng {
+    Arc::new(Mutex::new(rng))
+}
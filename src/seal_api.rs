@@ -0,0 +1,254 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Object-safe view of the seal client.
+//!
+//! [`BaseSealClient`] has six type parameters, which leak into every type that stores one.
+//! [`SealApi`] erases them: hold an `Arc<dyn SealApi>` in application code and swap in a test
+//! double in unit tests. The generic conveniences (`encrypt`, `decrypt_object`) live in
+//! [`SealApiExt`], which is implemented for every [`SealApi`], trait objects included.
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "client")]
+//! # {
+//! use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
+//! use seal_sdk_rs::seal_api::SealApi;
+//! use std::sync::Arc;
+//!
+//! struct Vault {
+//!     seal: Arc<dyn SealApi>,
+//! }
+//!
+//! let vault = Vault {
+//!     seal: Arc::new(JsonRpcSealClient::new("https://fullnode.testnet.sui.io:443")),
+//! };
+//! # let _ = vault.seal;
+//! # }
+//! ```
+
+use crate::base_client::{BaseSealClient, DerivedKeys, KeyServerConfig, KeyServerInfo};
+use crate::cache::SealCache;
+use crate::cache_key::{DerivedKeyCacheKey, KeyServerInfoCacheKey};
+use crate::crypto::EncryptedObject;
+use crate::error::SealClientError;
+use crate::generic_types::{BCSSerializableProgrammableTransaction, ObjectID};
+use crate::http_client::HttpClient;
+use crate::session_key::SessionKey;
+use crate::sui_client::SuiClient;
+use async_trait::async_trait;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// Approval transaction accepted by [`SealApi`] decryption methods.
+pub type ApproveTransaction<'a> = &'a (dyn BCSSerializableProgrammableTransaction + Sync);
+
+/// Object-safe subset of the [`BaseSealClient`] API.
+///
+/// Methods mirror their [`BaseSealClient`] namesakes, with concrete [`ObjectID`]s and the
+/// approval transaction passed as a trait object. Single-item methods default to their
+/// `multiple` counterparts, so a test double only needs to implement those.
+#[async_trait]
+pub trait SealApi: Send + Sync {
+    async fn get_key_server_info(
+        &self,
+        key_server_id: ObjectID,
+    ) -> Result<KeyServerInfo, SealClientError>;
+
+    async fn key_server_info(
+        &self,
+        key_servers: Vec<KeyServerConfig>,
+    ) -> Result<Vec<KeyServerInfo>, SealClientError>;
+
+    async fn encrypt_bytes(
+        &self,
+        package_id: ObjectID,
+        id: Vec<u8>,
+        threshold: u8,
+        key_servers: Vec<KeyServerConfig>,
+        data: Vec<u8>,
+    ) -> Result<(EncryptedObject, [u8; 32]), SealClientError> {
+        self.encrypt_multiple_bytes(package_id, id, threshold, key_servers, vec![data])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(SealClientError::MissingEncryptedObject)
+    }
+
+    async fn encrypt_multiple_bytes(
+        &self,
+        package_id: ObjectID,
+        id: Vec<u8>,
+        threshold: u8,
+        key_servers: Vec<KeyServerConfig>,
+        data: Vec<Vec<u8>>,
+    ) -> Result<Vec<(EncryptedObject, [u8; 32])>, SealClientError>;
+
+    async fn decrypt_object_bytes(
+        &self,
+        encrypted_object_data: &[u8],
+        approve_transaction_data: ApproveTransaction<'_>,
+        session_key: &SessionKey,
+        aggregator_urls_for_ker_server: HashMap<ObjectID, String>,
+    ) -> Result<Vec<u8>, SealClientError> {
+        self.decrypt_multiple_objects_bytes(
+            &[encrypted_object_data],
+            approve_transaction_data,
+            session_key,
+            aggregator_urls_for_ker_server,
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or(SealClientError::MissingDecryptedObject)
+    }
+
+    async fn decrypt_multiple_objects_bytes(
+        &self,
+        encrypted_objects_data: &[&[u8]],
+        approve_transaction_data: ApproveTransaction<'_>,
+        session_key: &SessionKey,
+        aggregator_urls_for_ker_server: HashMap<ObjectID, String>,
+    ) -> Result<Vec<Vec<u8>>, SealClientError>;
+}
+
+/// BCS-typed helpers available on every [`SealApi`], including `dyn SealApi`.
+#[async_trait]
+pub trait SealApiExt: SealApi {
+    async fn encrypt<T>(
+        &self,
+        package_id: ObjectID,
+        id: Vec<u8>,
+        threshold: u8,
+        key_servers: Vec<KeyServerConfig>,
+        data: T,
+    ) -> Result<(EncryptedObject, [u8; 32]), SealClientError>
+    where
+        T: Serialize + Send,
+    {
+        let data = bcs::to_bytes(&data)?;
+        self.encrypt_bytes(package_id, id, threshold, key_servers, data)
+            .await
+    }
+
+    async fn decrypt_object<T>(
+        &self,
+        encrypted_object_data: &[u8],
+        approve_transaction_data: ApproveTransaction<'_>,
+        session_key: &SessionKey,
+        aggregator_urls_for_ker_server: HashMap<ObjectID, String>,
+    ) -> Result<T, SealClientError>
+    where
+        T: DeserializeOwned,
+    {
+        let bytes = self
+            .decrypt_object_bytes(
+                encrypted_object_data,
+                approve_transaction_data,
+                session_key,
+                aggregator_urls_for_ker_server,
+            )
+            .await?;
+
+        Ok(bcs::from_bytes::<T>(&bytes)?)
+    }
+}
+
+impl<S: SealApi + ?Sized> SealApiExt for S {}
+
+#[async_trait]
+impl<KeyServerInfoCache, DerivedKeysCache, SuiError, Sui, HttpError, Http> SealApi
+    for BaseSealClient<KeyServerInfoCache, DerivedKeysCache, SuiError, Sui, HttpError, Http>
+where
+    KeyServerInfoCache: SealCache<Key = KeyServerInfoCacheKey, Value = KeyServerInfo> + Send + Sync,
+    DerivedKeysCache: SealCache<Key = DerivedKeyCacheKey, Value = DerivedKeys> + Send + Sync,
+    SealClientError: From<SuiError>,
+    SuiError: Send + Sync + Display + 'static,
+    Sui: SuiClient<Error = SuiError>,
+    SealClientError: From<HttpError>,
+    HttpError: Send + Sync + 'static,
+    Http: HttpClient<PostError = HttpError> + Send,
+{
+    async fn get_key_server_info(
+        &self,
+        key_server_id: ObjectID,
+    ) -> Result<KeyServerInfo, SealClientError> {
+        BaseSealClient::get_key_server_info(self, key_server_id).await
+    }
+
+    async fn key_server_info(
+        &self,
+        key_servers: Vec<KeyServerConfig>,
+    ) -> Result<Vec<KeyServerInfo>, SealClientError> {
+        BaseSealClient::key_server_info(self, key_servers).await
+    }
+
+    async fn encrypt_bytes(
+        &self,
+        package_id: ObjectID,
+        id: Vec<u8>,
+        threshold: u8,
+        key_servers: Vec<KeyServerConfig>,
+        data: Vec<u8>,
+    ) -> Result<(EncryptedObject, [u8; 32]), SealClientError> {
+        BaseSealClient::encrypt_bytes(self, package_id, id, threshold, key_servers, data).await
+    }
+
+    async fn encrypt_multiple_bytes(
+        &self,
+        package_id: ObjectID,
+        id: Vec<u8>,
+        threshold: u8,
+        key_servers: Vec<KeyServerConfig>,
+        data: Vec<Vec<u8>>,
+    ) -> Result<Vec<(EncryptedObject, [u8; 32])>, SealClientError> {
+        BaseSealClient::encrypt_multiple_bytes(self, package_id, id, threshold, key_servers, data)
+            .await
+    }
+
+    async fn decrypt_object_bytes(
+        &self,
+        encrypted_object_data: &[u8],
+        approve_transaction_data: ApproveTransaction<'_>,
+        session_key: &SessionKey,
+        aggregator_urls_for_ker_server: HashMap<ObjectID, String>,
+    ) -> Result<Vec<u8>, SealClientError> {
+        BaseSealClient::decrypt_object_bytes(
+            self,
+            encrypted_object_data,
+            approve_transaction_data,
+            session_key,
+            aggregator_urls_for_ker_server,
+        )
+        .await
+    }
+
+    async fn decrypt_multiple_objects_bytes(
+        &self,
+        encrypted_objects_data: &[&[u8]],
+        approve_transaction_data: ApproveTransaction<'_>,
+        session_key: &SessionKey,
+        aggregator_urls_for_ker_server: HashMap<ObjectID, String>,
+    ) -> Result<Vec<Vec<u8>>, SealClientError> {
+        BaseSealClient::decrypt_multiple_objects_bytes(
+            self,
+            encrypted_objects_data,
+            approve_transaction_data,
+            session_key,
+            aggregator_urls_for_ker_server,
+        )
+        .await
+    }
+}
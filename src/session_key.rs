@@ -12,19 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::clock::{Clock, SystemClock};
 use crate::crypto::{
     Certificate, ElGamalPublicKey, ElGamalSecretKey, ElgamalVerificationKey, FetchKeyRequest,
 };
 use crate::error::SessionKeyError;
 use crate::generic_types::{ObjectID, SuiAddress};
-use crate::signer::Signer;
+use crate::rng::SealRng;
+use crate::signer::{Signer, ed25519_sui_address};
 use base64::Engine;
 use chrono::{DateTime, Utc};
 use fastcrypto::ed25519::{Ed25519KeyPair, Ed25519PublicKey};
 use fastcrypto::traits::KeyPair;
-use rand::thread_rng;
+use rand::{CryptoRng, RngCore, thread_rng};
 use seal_crypto::elgamal::genkey;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use sui_sdk_types::{SimpleSignature, UserSignature};
 
 const MIN_TTL_MIN: u16 = 1;
@@ -83,6 +86,7 @@ struct RequestFormat {
 ///
 /// let session_key = SessionKey::new(
 ///     ObjectID([0; 32]),
+///     None,
 ///     5,
 ///     &mut signer,
 /// )
@@ -97,6 +101,7 @@ struct RequestFormat {
 pub struct SessionKey {
     address: SuiAddress,
     package_id: ObjectID,
+    mvr_name: Option<String>,
     creation_time_ms: u64,
     ttl_min: u16,
     session_key: Ed25519KeyPair,
@@ -109,8 +114,16 @@ impl SessionKey {
     ///
     /// This signs a capability with the wallet so subsequent decrypt calls can proceed
     /// without additional wallet signatures until the TTL expires.
+    ///
+    /// When `mvr_name` is set (e.g. `@org/app`), the Move Registry name replaces the raw
+    /// package id in the personal message shown to the user and is forwarded to key servers
+    /// in the certificate, mirroring the TypeScript SDK. Key servers resolve the name and
+    /// reject the certificate if it does not point to `package_id`.
+    ///
+    /// Use [`SessionKey::builder`] to customize the clock or compensate for clock skew.
     pub async fn new<ID, SigError, Sig>(
         package_id: ID,
+        mvr_name: Option<String>,
         ttl_min: u16,
         signer: &mut Sig,
     ) -> Result<SessionKey, SessionKeyError>
@@ -119,49 +132,50 @@ impl SessionKey {
         SessionKeyError: From<SigError>,
         Sig: Signer<Error = SigError>,
     {
-        let package_id: ObjectID = package_id.into();
-
-        if !(MIN_TTL_MIN..=MAX_TTL_MAX).contains(&ttl_min) {
-            return Err(SessionKeyError::InvalidTTLMin {
-                min: MIN_TTL_MIN,
-                max: MAX_TTL_MAX,
-                received: ttl_min,
-            });
-        };
-
-        let signer_address = signer.get_sui_address()?;
-        let signer_public_key = signer.get_public_key()?;
-
-        let session_key = Ed25519KeyPair::generate(&mut thread_rng());
+        let mut builder = SessionKey::builder(package_id, ttl_min);
+        builder.mvr_name = mvr_name;
 
-        let now_ms = Utc::now().timestamp_millis() as u64;
-
-        let Some(message_to_sign) = signed_message(
-            sui_sdk_types::ObjectId::from(package_id).to_string(),
-            session_key.public(),
-            now_ms,
-            ttl_min,
-        ) else {
-            return Err(SessionKeyError::CannotGenerateSignedMessage {
-                package_id,
-                creation_timestamp_ms: now_ms,
-                ttl_min,
-            });
-        };
-
-        let signature = signer
-            .sign_personal_message(message_to_sign.as_bytes().to_vec())
-            .await?;
+        builder.build(signer).await
+    }
 
-        Ok(SessionKey {
-            address: signer_address,
-            package_id,
-            creation_time_ms: chrono::Utc::now().timestamp_millis() as u64,
+    /// Start configuring a session key scoped to `package_id` and valid for `ttl_min` minutes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use seal_sdk_rs::clock::FixedClock;
+    /// use seal_sdk_rs::generic_types::ObjectID;
+    /// use seal_sdk_rs::session_key::SessionKey;
+    /// use seal_sdk_rs::signer::keypair::KeypairSigner;
+    /// use std::time::Duration;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> anyhow::Result<()> {
+    /// let mut signer = KeypairSigner::from_bech32("suiprivkey1...")?;
+    ///
+    /// let session_key = SessionKey::builder(ObjectID([0; 32]), 5)
+    ///     .mvr_name("@org/app")
+    ///     .clock(FixedClock::new(1_700_000_000_000))
+    ///     .clock_skew_compensation(Duration::from_secs(5))
+    ///     .build(&mut signer)
+    ///     .await?;
+    ///
+    /// assert_eq!(session_key.creation_time_ms(), 1_699_999_995_000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder<ID>(package_id: ID, ttl_min: u16) -> SessionKeyBuilder
+    where
+        ObjectID: From<ID>,
+    {
+        SessionKeyBuilder {
+            package_id: package_id.into(),
+            mvr_name: None,
             ttl_min,
-            session_key,
-            personal_message_signer_address_and_public_key: (signer_address, signer_public_key),
-            personal_message_signature: signature.sig.to_bytes(),
-        })
+            clock: SystemClock,
+            clock_skew_compensation: Duration::ZERO,
+            rng: None,
+        }
     }
 
     pub fn address(&self) -> &SuiAddress {
@@ -172,15 +186,51 @@ impl SessionKey {
         &self.package_id
     }
 
+    pub fn mvr_name(&self) -> Option<&str> {
+        self.mvr_name.as_deref()
+    }
+
+    /// Creation time written in the certificate, in milliseconds since the Unix epoch.
+    pub fn creation_time_ms(&self) -> u64 {
+        self.creation_time_ms
+    }
+
+    pub fn ttl_min(&self) -> u16 {
+        self.ttl_min
+    }
+
+    /// Instant after which key servers reject the certificate, in milliseconds since the
+    /// Unix epoch.
+    pub fn expiration_time_ms(&self) -> u64 {
+        self.creation_time_ms + u64::from(self.ttl_min) * 60_000
+    }
+
+    pub fn is_expired<C: Clock>(&self, clock: &C) -> bool {
+        clock.now_ms() >= self.expiration_time_ms()
+    }
+
     pub fn get_fetch_key_request(
         &self,
         approve_transaction_data: Vec<u8>,
     ) -> Result<(FetchKeyRequest, ElGamalSecretKey), SessionKeyError> {
+        self.get_fetch_key_request_with_rng(approve_transaction_data, &mut thread_rng())
+    }
+
+    /// Same as [`get_fetch_key_request`](Self::get_fetch_key_request), drawing the ephemeral
+    /// ElGamal keys from `rng`.
+    pub fn get_fetch_key_request_with_rng<R>(
+        &self,
+        approve_transaction_data: Vec<u8>,
+        rng: &mut R,
+    ) -> Result<(FetchKeyRequest, ElGamalSecretKey), SessionKeyError>
+    where
+        R: RngCore + CryptoRng + ?Sized,
+    {
         let approve_transaction_data_base64 =
             base64::engine::general_purpose::STANDARD.encode(&approve_transaction_data);
 
         let (signed_request, enc_secret, enc_key, enc_verification_key) =
-            self.get_signed_request(approve_transaction_data)?;
+            self.get_signed_request(approve_transaction_data, rng)?;
 
         let request_signature =
             fastcrypto::traits::Signer::sign(&self.session_key, &signed_request);
@@ -196,9 +246,10 @@ impl SessionKey {
         Ok((result, enc_secret))
     }
 
-    fn get_signed_request(
+    fn get_signed_request<R>(
         &self,
         approve_transaction_data: Vec<u8>,
+        mut rng: &mut R,
     ) -> Result<
         (
             Vec<u8>,
@@ -207,16 +258,19 @@ impl SessionKey {
             ElgamalVerificationKey,
         ),
         SessionKeyError,
-    > {
-        let keys: (_, ElGamalPublicKey, ElgamalVerificationKey) = genkey(&mut rand::thread_rng());
+    >
+    where
+        R: RngCore + CryptoRng + ?Sized,
+    {
+        let keys: (_, ElGamalPublicKey, ElgamalVerificationKey) = genkey(&mut rng);
 
-        let req = RequestFormat {
-            ptb: approve_transaction_data,
-            enc_key: bcs::to_bytes(&keys.1)?,
-            enc_verification_key: bcs::to_bytes(&keys.2)?,
-        };
+        let signed_request = signed_request(
+            approve_transaction_data,
+            bcs::to_bytes(&keys.1)?,
+            bcs::to_bytes(&keys.2)?,
+        )?;
 
-        Ok((bcs::to_bytes(&req)?, keys.0, keys.1, keys.2))
+        Ok((signed_request, keys.0, keys.1, keys.2))
     }
 
     fn get_certificate(&self) -> Certificate {
@@ -237,9 +291,135 @@ impl SessionKey {
                         .to_bytes(),
                 ),
             }),
-            mvr_name: None,
+            mvr_name: self.mvr_name.clone(),
+        }
+    }
+}
+
+/// Configures and mints a [`SessionKey`], see [`SessionKey::builder`].
+pub struct SessionKeyBuilder<C = SystemClock> {
+    package_id: ObjectID,
+    mvr_name: Option<String>,
+    ttl_min: u16,
+    clock: C,
+    clock_skew_compensation: Duration,
+    rng: Option<Box<dyn SealRng>>,
+}
+
+impl<C: Clock> SessionKeyBuilder<C> {
+    /// Move Registry name displayed instead of the package id, see [`SessionKey::new`].
+    pub fn mvr_name<S: Into<String>>(mut self, mvr_name: S) -> Self {
+        self.mvr_name = Some(mvr_name.into());
+        self
+    }
+
+    /// Replace the [`SystemClock`] used to timestamp the certificate.
+    pub fn clock<NewClock: Clock>(self, clock: NewClock) -> SessionKeyBuilder<NewClock> {
+        SessionKeyBuilder {
+            package_id: self.package_id,
+            mvr_name: self.mvr_name,
+            ttl_min: self.ttl_min,
+            clock,
+            clock_skew_compensation: self.clock_skew_compensation,
+            rng: self.rng,
         }
     }
+
+    /// Backdate the certificate creation time by `backdate`.
+    ///
+    /// Key servers reject certificates created in the future, so a client whose clock runs
+    /// ahead of theirs fails until the drift is absorbed. The TTL window still starts at the
+    /// backdated instant, so the effective lifetime shrinks by the same amount.
+    pub fn clock_skew_compensation(mut self, backdate: Duration) -> Self {
+        self.clock_skew_compensation = backdate;
+        self
+    }
+
+    /// Generate the ephemeral session keypair from `rng` instead of `thread_rng()`.
+    ///
+    /// Together with a fixed [`clock`](Self::clock) and a deterministic signer, this yields
+    /// byte-identical session keys and certificates, e.g. for test vectors.
+    pub fn rng<R: SealRng + 'static>(mut self, rng: R) -> Self {
+        self.rng = Some(Box::new(rng));
+        self
+    }
+
+    /// Sign the certificate with `signer` and return the session key.
+    pub async fn build<SigError, Sig>(self, signer: &mut Sig) -> Result<SessionKey, SessionKeyError>
+    where
+        SessionKeyError: From<SigError>,
+        Sig: Signer<Error = SigError>,
+    {
+        let SessionKeyBuilder {
+            package_id,
+            mvr_name,
+            ttl_min,
+            clock,
+            clock_skew_compensation,
+            rng,
+        } = self;
+
+        if !(MIN_TTL_MIN..=MAX_TTL_MAX).contains(&ttl_min) {
+            return Err(SessionKeyError::InvalidTTLMin {
+                min: MIN_TTL_MIN,
+                max: MAX_TTL_MAX,
+                received: ttl_min,
+            });
+        };
+
+        let signer_address = signer.get_sui_address()?;
+        let signer_public_key = signer.get_public_key()?;
+
+        let derived_address = ed25519_sui_address(&signer_public_key);
+        if signer_address != derived_address {
+            return Err(SessionKeyError::SignerAddressMismatch {
+                claimed: signer_address,
+                derived: derived_address,
+            });
+        }
+
+        let session_key = match rng {
+            Some(mut rng) => Ed25519KeyPair::generate(&mut rng),
+            None => Ed25519KeyPair::generate(&mut thread_rng()),
+        };
+
+        // Read the clock once so the signed message and the certificate agree.
+        let creation_time_ms = clock
+            .now_ms()
+            .saturating_sub(clock_skew_compensation.as_millis() as u64);
+
+        let package_name = mvr_name
+            .clone()
+            .unwrap_or_else(|| sui_sdk_types::ObjectId::from(package_id).to_string());
+
+        let Some(message_to_sign) = signed_message(
+            package_name,
+            session_key.public(),
+            creation_time_ms,
+            ttl_min,
+        ) else {
+            return Err(SessionKeyError::CannotGenerateSignedMessage {
+                package_id,
+                creation_timestamp_ms: creation_time_ms,
+                ttl_min,
+            });
+        };
+
+        let signature = signer
+            .sign_personal_message(message_to_sign.as_bytes().to_vec())
+            .await?;
+
+        Ok(SessionKey {
+            address: signer_address,
+            package_id,
+            mvr_name,
+            creation_time_ms,
+            ttl_min,
+            session_key,
+            personal_message_signer_address_and_public_key: (signer_address, signer_public_key),
+            personal_message_signature: signature.sig.to_bytes(),
+        })
+    }
 }
 
 pub fn signed_message(
@@ -258,3 +438,18 @@ pub fn signed_message(
 
     Some(res)
 }
+
+/// Bytes signed by the session key in a fetch key request.
+///
+/// `enc_key` and `enc_verification_key` are the encoded ElGamal keys, as sent in the request.
+pub fn signed_request(
+    ptb: Vec<u8>,
+    enc_key: Vec<u8>,
+    enc_verification_key: Vec<u8>,
+) -> Result<Vec<u8>, bcs::Error> {
+    bcs::to_bytes(&RequestFormat {
+        ptb,
+        enc_key,
+        enc_verification_key,
+    })
+}
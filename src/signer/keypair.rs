@@ -0,0 +1,165 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::generic_types::SuiAddress;
+use crate::signer::{ED25519_FLAG, Signer, ed25519_sui_address};
+use async_trait::async_trait;
+use fastcrypto::ed25519::{
+    ED25519_PRIVATE_KEY_LENGTH, Ed25519KeyPair, Ed25519PrivateKey, Ed25519PublicKey,
+    Ed25519Signature,
+};
+use fastcrypto::encoding::Bech32;
+use fastcrypto::hash::{Blake2b256, HashFunction};
+use fastcrypto::traits::{KeyPair, ToFromBytes};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Human readable part of Bech32-encoded Sui private keys.
+pub const SUI_PRIVATE_KEY_PREFIX: &str = "suiprivkey";
+
+/// Intent prefix (`PersonalMessage` scope, version 0, Sui app) prepended to personal messages.
+const PERSONAL_MESSAGE_INTENT: [u8; 3] = [3, 0, 0];
+
+#[derive(Debug, Error)]
+pub enum KeypairSignerError {
+    #[error("Invalid Bech32 private key: {reason}")]
+    InvalidBech32 { reason: String },
+
+    #[error("Unsupported signature scheme flag {flag}, only Ed25519 keys are supported")]
+    UnsupportedScheme { flag: u8 },
+
+    #[error("Invalid private key length: expected 33 bytes (flag + key), got {length}")]
+    InvalidKeyLength { length: usize },
+
+    #[error("FastCrypto error: {0}")]
+    FastCrypto(#[from] fastcrypto::error::FastCryptoError),
+
+    #[error("BCS error: {0}")]
+    BCS(#[from] bcs::Error),
+}
+
+/// [`Signer`] backed by an Ed25519 keypair held in memory.
+///
+/// Useful for servers and tests that need to mint session keys without a Sui client
+/// config directory. Keys can be provided directly or as a `suiprivkey1...` string, the
+/// format printed by `sui keytool export`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use seal_sdk_rs::generic_types::ObjectID;
+/// use seal_sdk_rs::session_key::SessionKey;
+/// use seal_sdk_rs::signer::keypair::KeypairSigner;
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// let mut signer = KeypairSigner::from_bech32("suiprivkey1...")?;
+///
+/// let session_key = SessionKey::new(ObjectID([0; 32]), None, 5, &mut signer).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct KeypairSigner {
+    keypair: Ed25519KeyPair,
+}
+
+impl KeypairSigner {
+    pub fn new(keypair: Ed25519KeyPair) -> Self {
+        Self { keypair }
+    }
+
+    /// Decode a Bech32 `suiprivkey` string into a signer.
+    pub fn from_bech32(value: &str) -> Result<Self, KeypairSignerError> {
+        let bytes = Bech32::decode(value, SUI_PRIVATE_KEY_PREFIX).map_err(|err| {
+            KeypairSignerError::InvalidBech32 {
+                reason: err.to_string(),
+            }
+        })?;
+
+        Self::from_flagged_bytes(&bytes)
+    }
+
+    /// Build a signer from `flag || private_key` bytes, the layout used by both `suiprivkey`
+    /// strings and `sui.keystore` entries.
+    pub fn from_flagged_bytes(bytes: &[u8]) -> Result<Self, KeypairSignerError> {
+        let Some((flag, private_key)) = bytes.split_first() else {
+            return Err(KeypairSignerError::InvalidKeyLength { length: 0 });
+        };
+
+        if *flag != ED25519_FLAG {
+            return Err(KeypairSignerError::UnsupportedScheme { flag: *flag });
+        }
+
+        if private_key.len() != ED25519_PRIVATE_KEY_LENGTH {
+            return Err(KeypairSignerError::InvalidKeyLength {
+                length: bytes.len(),
+            });
+        }
+
+        let private_key = Ed25519PrivateKey::from_bytes(private_key)?;
+
+        Ok(Self::new(Ed25519KeyPair::from(private_key)))
+    }
+
+    pub fn keypair(&self) -> &Ed25519KeyPair {
+        &self.keypair
+    }
+
+    pub fn address(&self) -> SuiAddress {
+        ed25519_sui_address(self.keypair.public())
+    }
+}
+
+impl FromStr for KeypairSigner {
+    type Err = KeypairSignerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bech32(s)
+    }
+}
+
+impl From<Ed25519KeyPair> for KeypairSigner {
+    fn from(value: Ed25519KeyPair) -> Self {
+        Self::new(value)
+    }
+}
+
+#[async_trait]
+impl Signer for KeypairSigner {
+    type Error = KeypairSignerError;
+
+    async fn sign_personal_message(
+        &mut self,
+        message: Vec<u8>,
+    ) -> Result<Ed25519Signature, KeypairSignerError> {
+        // Wallets sign the Blake2b-256 digest of the intent followed by the BCS-encoded message.
+        let mut hasher = Blake2b256::default();
+        hasher.update(PERSONAL_MESSAGE_INTENT);
+        hasher.update(bcs::to_bytes(&message)?);
+        let digest = hasher.finalize();
+
+        Ok(fastcrypto::traits::Signer::sign(
+            &self.keypair,
+            &digest.digest,
+        ))
+    }
+
+    fn get_public_key(&mut self) -> Result<Ed25519PublicKey, KeypairSignerError> {
+        Ok(self.keypair.public().clone())
+    }
+
+    fn get_sui_address(&mut self) -> Result<SuiAddress, KeypairSignerError> {
+        Ok(self.address())
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::generic_types::SuiAddress;
+use crate::signer::Signer;
+use crate::signer::keypair::{KeypairSigner, KeypairSignerError};
+use async_trait::async_trait;
+use base64::Engine;
+use fastcrypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum KeystoreSignerError {
+    #[error("Cannot read keystore file {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("Invalid keystore file {path}: {source}")]
+    InvalidKeystore {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+
+    #[error("Invalid base64 keystore entry: {0}")]
+    Base64(#[from] base64::DecodeError),
+
+    #[error("Invalid keystore entry: {0}")]
+    Keypair(#[from] KeypairSignerError),
+
+    #[error("No Ed25519 key for address {address} in keystore file {path}")]
+    AddressNotFound { address: SuiAddress, path: PathBuf },
+}
+
+/// [`Signer`] reading its key from a `sui.keystore` file.
+///
+/// The keystore is the JSON array of base64 `flag || private_key` entries maintained by the
+/// Sui CLI (usually `~/.sui/sui_config/sui.keystore`). The file is read once at construction
+/// and the key matching `address` is kept in memory; entries using a scheme other than
+/// Ed25519 are skipped.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use seal_sdk_rs::generic_types::{ObjectID, SuiAddress};
+/// use seal_sdk_rs::session_key::SessionKey;
+/// use seal_sdk_rs::signer::keystore::FileKeystoreSigner;
+///
+/// # #[tokio::main]
+/// # async fn main() -> anyhow::Result<()> {
+/// # let address = SuiAddress([0; 32]);
+/// let mut signer = FileKeystoreSigner::new("/home/me/.sui/sui_config/sui.keystore", address)?;
+///
+/// let session_key = SessionKey::new(ObjectID([0; 32]), None, 5, &mut signer).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct FileKeystoreSigner {
+    signer: KeypairSigner,
+}
+
+impl FileKeystoreSigner {
+    pub fn new<P: AsRef<Path>>(path: P, address: SuiAddress) -> Result<Self, KeystoreSignerError> {
+        let path = path.as_ref();
+
+        let content = std::fs::read_to_string(path).map_err(|source| KeystoreSignerError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        let entries: Vec<String> = serde_json::from_str(&content).map_err(|source| {
+            KeystoreSignerError::InvalidKeystore {
+                path: path.to_path_buf(),
+                source,
+            }
+        })?;
+
+        for entry in entries {
+            let bytes = base64::engine::general_purpose::STANDARD.decode(entry)?;
+
+            let signer = match KeypairSigner::from_flagged_bytes(&bytes) {
+                Ok(signer) => signer,
+                Err(KeypairSignerError::UnsupportedScheme { .. }) => continue,
+                Err(err) => return Err(err.into()),
+            };
+
+            if signer.address() == address {
+                return Ok(Self { signer });
+            }
+        }
+
+        Err(KeystoreSignerError::AddressNotFound {
+            address,
+            path: path.to_path_buf(),
+        })
+    }
+
+    pub fn address(&self) -> SuiAddress {
+        self.signer.address()
+    }
+}
+
+#[async_trait]
+impl Signer for FileKeystoreSigner {
+    type Error = KeypairSignerError;
+
+    async fn sign_personal_message(
+        &mut self,
+        message: Vec<u8>,
+    ) -> Result<Ed25519Signature, KeypairSignerError> {
+        self.signer.sign_personal_message(message).await
+    }
+
+    fn get_public_key(&mut self) -> Result<Ed25519PublicKey, KeypairSignerError> {
+        self.signer.get_public_key()
+    }
+
+    fn get_sui_address(&mut self) -> Result<SuiAddress, KeypairSignerError> {
+        self.signer.get_sui_address()
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod keypair;
+pub mod keystore;
+
+use crate::generic_types::SuiAddress;
+use async_trait::async_trait;
+use fastcrypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
+use fastcrypto::hash::{Blake2b256, HashFunction};
+
+/// Signature scheme flag prepended to Ed25519 public keys when deriving Sui addresses.
+pub const ED25519_FLAG: u8 = 0x00;
+
+/// Abstraction over the minimal signing capabilities needed to mint `SessionKey`s.
+///
+/// The trait captures the ability to produce personal-message signatures together with
+/// the caller's public key and Sui address. In-memory keys are covered by
+/// [`KeypairSigner`](keypair::KeypairSigner) and `sui.keystore` files by
+/// [`FileKeystoreSigner`](keystore::FileKeystoreSigner). When the crate is compiled with the
+/// relevant feature flags, an implementation for `sui_sdk::wallet_context::WalletContext` is
+/// provided out of the box.
+#[async_trait]
+pub trait Signer {
+    type Error;
+
+    async fn sign_personal_message(
+        &mut self,
+        message: Vec<u8>,
+    ) -> Result<Ed25519Signature, Self::Error>;
+
+    fn get_public_key(&mut self) -> Result<Ed25519PublicKey, Self::Error>;
+
+    /// Returns the Sui address owning the signing key.
+    ///
+    /// Defaults to the address derived from [`get_public_key`](Signer::get_public_key).
+    /// Override it only when the address can be obtained more cheaply; `SessionKey::new`
+    /// rejects signers whose claimed address does not match their public key.
+    fn get_sui_address(&mut self) -> Result<SuiAddress, Self::Error> {
+        Ok(ed25519_sui_address(&self.get_public_key()?))
+    }
+}
+
+/// Derive the Sui address controlled by an Ed25519 public key.
+///
+/// Sui addresses are the Blake2b-256 hash of the signature scheme flag followed by the
+/// raw public key bytes.
+pub fn ed25519_sui_address(public_key: &Ed25519PublicKey) -> SuiAddress {
+    let mut hasher = Blake2b256::default();
+    hasher.update([ED25519_FLAG]);
+    hasher.update(public_key.as_ref());
+
+    SuiAddress(hasher.finalize().digest)
+}
@@ -14,16 +14,19 @@
 
 use crate::base_client::KeyServerInfo;
 use async_trait::async_trait;
+use futures::future::join_all;
 use std::fmt::Display;
+use std::sync::Arc;
 
 /// Abstraction over the Sui JSON-RPC calls needed by the seal client.
 ///
 /// The trait mirrors the signatures exposed by `sui_sdk::SuiClient` and provides
 /// just enough surface area for [`BaseSealClient`](crate::base_client::BaseSealClient) to
 /// retrieve key-server metadata required during encryption and decryption workflows.
-/// When the crate is built with the `client` and `native-sui-sdk` features (enabled
-/// by default), an implementation backed by `sui_sdk::SuiClient` lives in
-/// [`native_sui_sdk::client::sui_client`](crate::native_sui_sdk::client::sui_client).
+/// The default implementation is [`JsonRpcSuiClient`](crate::json_rpc::sui_client::JsonRpcSuiClient),
+/// which only needs the crate's [`HttpClient`](crate::http_client::HttpClient). With the
+/// `native-sui-sdk` feature, an implementation backed by `sui_sdk::SuiClient` lives in
+/// `native_sui_sdk::client::sui_client`.
 #[async_trait]
 pub trait SuiClient: Send + Sync {
     type Error: Display + Send + Sync;
@@ -32,4 +35,43 @@ pub trait SuiClient: Send + Sync {
         &self,
         key_server_id: [u8; 32],
     ) -> Result<KeyServerInfo, Self::Error>;
+
+    /// Resolve several key servers at once, returning their info in the order of
+    /// `key_server_ids`.
+    ///
+    /// The default implementation runs [`get_key_server_info`](Self::get_key_server_info)
+    /// concurrently for every id. Backends with a multi-get endpoint override it so that `N`
+    /// servers cost one request per key server version instead of `N` lookups each.
+    async fn get_key_servers_info(
+        &self,
+        key_server_ids: Vec<[u8; 32]>,
+    ) -> Result<Vec<KeyServerInfo>, Self::Error> {
+        join_all(
+            key_server_ids
+                .into_iter()
+                .map(|key_server_id| self.get_key_server_info(key_server_id)),
+        )
+        .await
+        .into_iter()
+        .collect()
+    }
+}
+
+#[async_trait]
+impl<T: SuiClient> SuiClient for Arc<T> {
+    type Error = T::Error;
+
+    async fn get_key_server_info(
+        &self,
+        key_server_id: [u8; 32],
+    ) -> Result<KeyServerInfo, Self::Error> {
+        (**self).get_key_server_info(key_server_id).await
+    }
+
+    async fn get_key_servers_info(
+        &self,
+        key_server_ids: Vec<[u8; 32]>,
+    ) -> Result<Vec<KeyServerInfo>, Self::Error> {
+        (**self).get_key_servers_info(key_server_ids).await
+    }
 }
@@ -0,0 +1,140 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use seal_sdk_rs::approve_transaction::{
+    SealApproveCall, check_approve_transaction, seal_approve_calls,
+};
+use seal_sdk_rs::error::ApproveTransactionError;
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::mock_key_server::seal_approve_transaction;
+use sui_sdk_types::{
+    Argument, Command, Identifier, Input, MoveCall, ProgrammableTransaction, TransferObjects,
+};
+
+const PACKAGE_ID: ObjectID = ObjectID([0xaa; 32]);
+const OTHER_PACKAGE_ID: ObjectID = ObjectID([0xbb; 32]);
+
+fn move_call(package_id: ObjectID, function: &str, input: u16) -> Command {
+    Command::MoveCall(MoveCall {
+        package: package_id.into(),
+        module: Identifier::new("policy").unwrap(),
+        function: Identifier::new(function).unwrap(),
+        type_arguments: vec![],
+        arguments: vec![Argument::Input(input)],
+    })
+}
+
+fn ptb_bytes(commands: Vec<Command>) -> Vec<u8> {
+    bcs::to_bytes(&ProgrammableTransaction {
+        inputs: vec![Input::Pure {
+            value: bcs::to_bytes(&vec![1u8, 2, 3]).unwrap(),
+        }],
+        commands,
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_seal_approve_calls() -> anyhow::Result<()> {
+    let ptb = seal_approve_transaction(PACKAGE_ID, "policy", &[vec![1, 2, 3], vec![4]])?;
+    let calls = seal_approve_calls(&bcs::to_bytes(&ptb)?)?;
+
+    assert_eq!(
+        calls,
+        vec![
+            SealApproveCall {
+                package_id: PACKAGE_ID,
+                module: "policy".to_string(),
+                function: "seal_approve".to_string(),
+                id: vec![1, 2, 3],
+            },
+            SealApproveCall {
+                package_id: PACKAGE_ID,
+                module: "policy".to_string(),
+                function: "seal_approve".to_string(),
+                id: vec![4],
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_seal_approve_calls_rejects_invalid_transactions() {
+    assert!(matches!(
+        seal_approve_calls(&[0xde, 0xad]),
+        Err(ApproveTransactionError::BCS(_))
+    ));
+    assert!(matches!(
+        seal_approve_calls(&ptb_bytes(vec![])),
+        Err(ApproveTransactionError::NoSealApproveCall)
+    ));
+    assert!(matches!(
+        seal_approve_calls(&ptb_bytes(vec![
+            move_call(PACKAGE_ID, "seal_approve", 0),
+            Command::TransferObjects(TransferObjects {
+                objects: vec![],
+                address: Argument::Input(0),
+            }),
+        ])),
+        Err(ApproveTransactionError::NotMoveCall { index: 1 })
+    ));
+    match seal_approve_calls(&ptb_bytes(vec![move_call(PACKAGE_ID, "withdraw", 0)])) {
+        Err(ApproveTransactionError::NotSealApprove { index, function }) => {
+            assert_eq!(index, 0);
+            assert_eq!(function, "withdraw");
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+    assert!(matches!(
+        seal_approve_calls(&ptb_bytes(vec![move_call(PACKAGE_ID, "seal_approve", 1)])),
+        Err(ApproveTransactionError::MissingId { index: 0 })
+    ));
+    assert!(matches!(
+        seal_approve_calls(&ptb_bytes(vec![
+            move_call(PACKAGE_ID, "seal_approve", 0),
+            move_call(OTHER_PACKAGE_ID, "seal_approve_owner", 0),
+        ])),
+        Err(ApproveTransactionError::MultiplePackages {
+            first: PACKAGE_ID,
+            other: OTHER_PACKAGE_ID
+        })
+    ));
+}
+
+#[test]
+fn test_check_approve_transaction_package() -> anyhow::Result<()> {
+    let ptb = bcs::to_bytes(&seal_approve_transaction(
+        OTHER_PACKAGE_ID,
+        "policy",
+        &[vec![1, 2, 3]],
+    )?)?;
+
+    assert!(matches!(
+        check_approve_transaction(&ptb, &PACKAGE_ID, Some(&[])),
+        Err(ApproveTransactionError::PackageMismatch {
+            package_id: OTHER_PACKAGE_ID,
+            session_package_id: PACKAGE_ID
+        })
+    ));
+
+    let calls = check_approve_transaction(&ptb, &PACKAGE_ID, Some(&[OTHER_PACKAGE_ID]))?;
+    assert_eq!(calls[0].package_id, OTHER_PACKAGE_ID);
+
+    // Without registered versions, upgrades are left to the key servers.
+    let calls = check_approve_transaction(&ptb, &PACKAGE_ID, None)?;
+    assert_eq!(calls[0].package_id, OTHER_PACKAGE_ID);
+
+    Ok(())
+}
@@ -0,0 +1,281 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::utils::static_sui_client::{SEAL_PACKAGE, StaticSuiClient, key_server_info};
+use async_trait::async_trait;
+use seal_sdk_rs::base_client::KeyServerConfig;
+use seal_sdk_rs::builder::{SealClientBuilder, SealClientBuilderError};
+use seal_sdk_rs::error::{ReqwestError, SealClientError};
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::http_client::{HttpClient, HttpResponse, PostResponse};
+use seal_sdk_rs::retry::RetryPolicy;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+pub mod utils;
+
+/// Answers GET requests with scripted responses, then with `404 Not Found`.
+#[derive(Clone, Default)]
+struct ScriptedHttpClient {
+    responses: Arc<Mutex<VecDeque<HttpResponse>>>,
+    requests: Arc<Mutex<usize>>,
+    delay: Option<Duration>,
+}
+
+impl ScriptedHttpClient {
+    fn new(responses: impl IntoIterator<Item = HttpResponse>) -> Self {
+        Self {
+            responses: Arc::new(Mutex::new(responses.into_iter().collect())),
+            ..Default::default()
+        }
+    }
+
+    fn requests(&self) -> usize {
+        *self.requests.lock().unwrap()
+    }
+}
+
+#[async_trait]
+impl HttpClient for ScriptedHttpClient {
+    type PostError = ReqwestError;
+
+    async fn post<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+        _body: S,
+    ) -> Result<PostResponse, Self::PostError> {
+        panic!("Unexpected POST to {url}");
+    }
+
+    async fn get(
+        &self,
+        url: &str,
+        headers: HashMap<String, String>,
+    ) -> Result<PostResponse, Self::PostError> {
+        self.get_with_headers(url, headers).await.map(Into::into)
+    }
+
+    async fn get_with_headers(
+        &self,
+        _url: &str,
+        _headers: HashMap<String, String>,
+    ) -> Result<HttpResponse, Self::PostError> {
+        *self.requests.lock().unwrap() += 1;
+
+        if let Some(delay) = self.delay {
+            tokio::time::sleep(delay).await;
+        }
+
+        let response = self.responses.lock().unwrap().pop_front();
+
+        Ok(response.unwrap_or_else(|| HttpResponse::new(404, "Not Found")))
+    }
+}
+
+#[test]
+fn test_builder_applies_configuration() -> anyhow::Result<()> {
+    let key_server = KeyServerConfig::new(
+        ObjectID([1; 32]),
+        Some("https://aggregator.example.com".to_string()),
+    );
+
+    let client = SealClientBuilder::new()
+        .sui_client(StaticSuiClient)
+        .key_server(key_server)
+        .trusted_seal_packages([SEAL_PACKAGE])
+        .verify_key_servers(true)
+        .retry_policy(RetryPolicy::new(4))
+        .request_timeout(Duration::from_secs(3))
+        .build()?;
+
+    assert_eq!(client.key_servers().len(), 1);
+    assert_eq!(client.key_servers()[0].object_id(), ObjectID([1; 32]));
+    assert_eq!(
+        client.key_servers()[0].aggregator_url(),
+        Some("https://aggregator.example.com")
+    );
+    assert_eq!(client.trusted_seal_packages(), Some(&[SEAL_PACKAGE][..]));
+    assert!(client.key_server_verification());
+    assert_eq!(client.retry_policy().max_attempts, 4);
+    assert_eq!(client.request_timeout(), Some(Duration::from_secs(3)));
+
+    let defaults = SealClientBuilder::new()
+        .sui_client(StaticSuiClient)
+        .build()?;
+
+    assert!(defaults.key_servers().is_empty());
+    assert_eq!(defaults.trusted_seal_packages(), None);
+    assert!(!defaults.key_server_verification());
+    assert_eq!(defaults.retry_policy(), RetryPolicy::none());
+    assert_eq!(defaults.request_timeout(), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_builder_rejects_invalid_configuration() {
+    let builder = || SealClientBuilder::new().sui_client(StaticSuiClient);
+
+    let duplicate = KeyServerConfig::new(ObjectID([1; 32]), None);
+    assert_eq!(
+        builder()
+            .key_servers([duplicate.clone(), duplicate])
+            .build()
+            .err(),
+        Some(SealClientBuilderError::DuplicateKeyServer {
+            object_id: ObjectID([1; 32])
+        })
+    );
+
+    assert_eq!(
+        builder()
+            .key_server(KeyServerConfig::new(
+                ObjectID([2; 32]),
+                Some("aggregator.example.com".to_string())
+            ))
+            .build()
+            .err(),
+        Some(SealClientBuilderError::InvalidAggregatorUrl {
+            object_id: ObjectID([2; 32]),
+            url: "aggregator.example.com".to_string(),
+        })
+    );
+
+    assert_eq!(
+        builder().retry_policy(RetryPolicy::new(0)).build().err(),
+        Some(SealClientBuilderError::ZeroRetryAttempts)
+    );
+
+    assert!(matches!(
+        builder()
+            .retry_policy(
+                RetryPolicy::new(2).with_backoff(Duration::from_secs(5), Duration::from_secs(1))
+            )
+            .build(),
+        Err(SealClientBuilderError::InvalidRetryBackoff { .. })
+    ));
+
+    assert_eq!(
+        builder().request_timeout(Duration::ZERO).build().err(),
+        Some(SealClientBuilderError::ZeroRequestTimeout)
+    );
+
+    assert_eq!(
+        builder()
+            .trusted_seal_packages(Vec::<ObjectID>::new())
+            .build()
+            .err(),
+        Some(SealClientBuilderError::NoTrustedSealPackages)
+    );
+}
+
+#[test]
+fn test_retry_policy_backoff() {
+    let policy =
+        RetryPolicy::new(5).with_backoff(Duration::from_millis(100), Duration::from_millis(350));
+
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(2), Duration::from_millis(200));
+    assert_eq!(policy.backoff(3), Duration::from_millis(350));
+    assert_eq!(policy.backoff(40), Duration::from_millis(350));
+
+    assert_eq!(policy.retry_after("0"), Some(Duration::ZERO));
+    assert_eq!(
+        policy.retry_after(" 120 "),
+        Some(Duration::from_millis(350))
+    );
+    assert_eq!(policy.retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+
+    assert!(RetryPolicy::is_retryable_status(429));
+    assert!(RetryPolicy::is_retryable_status(503));
+    assert!(!RetryPolicy::is_retryable_status(404));
+}
+
+#[tokio::test]
+async fn test_key_server_requests_are_retried() -> anyhow::Result<()> {
+    let retry_policy =
+        RetryPolicy::new(3).with_backoff(Duration::from_millis(1), Duration::from_millis(10));
+    let scripted = || {
+        ScriptedHttpClient::new([
+            HttpResponse::new(503, "Unavailable").with_header("Retry-After", "0"),
+            HttpResponse::new(500, "Internal Server Error"),
+        ])
+    };
+
+    // Retryable failures are retried until a non-retryable answer.
+    let http_client = scripted();
+    let result = SealClientBuilder::new()
+        .sui_client(StaticSuiClient)
+        .http_client(http_client.clone())
+        .retry_policy(retry_policy)
+        .build()?
+        .verify_key_servers(&[key_server_info(ObjectID([1; 32]))])
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::ErrorWhileVerifyingKeyServer { status: 404, .. })
+    ));
+    assert_eq!(http_client.requests(), 3);
+
+    // The last failure is reported once the attempts are exhausted.
+    let http_client = scripted();
+    let result = SealClientBuilder::new()
+        .sui_client(StaticSuiClient)
+        .http_client(http_client.clone())
+        .retry_policy(RetryPolicy {
+            max_attempts: 2,
+            ..retry_policy
+        })
+        .build()?
+        .verify_key_servers(&[key_server_info(ObjectID([1; 32]))])
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::ErrorWhileVerifyingKeyServer { status: 500, .. })
+    ));
+    assert_eq!(http_client.requests(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_key_server_requests_time_out() -> anyhow::Result<()> {
+    let http_client = ScriptedHttpClient {
+        delay: Some(Duration::from_secs(30)),
+        ..Default::default()
+    };
+
+    let result = SealClientBuilder::new()
+        .sui_client(StaticSuiClient)
+        .http_client(http_client.clone())
+        .request_timeout(Duration::from_millis(20))
+        .retry_policy(
+            RetryPolicy::new(2).with_backoff(Duration::from_millis(1), Duration::from_millis(1)),
+        )
+        .build()?
+        .verify_key_servers(&[key_server_info(ObjectID([1; 32]))])
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::KeyServerTimeout { timeout, .. }) if timeout == Duration::from_millis(20)
+    ));
+    assert_eq!(http_client.requests(), 2);
+
+    Ok(())
+}
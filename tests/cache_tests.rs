@@ -0,0 +1,79 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use seal_sdk_rs::cache::{NoCache, SealCache};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[tokio::test]
+async fn test_hash_map_cache_get_does_not_initialize() {
+    let cache: Arc<Mutex<HashMap<u8, u64>>> = Arc::default();
+
+    assert_eq!(cache.get(1).await, None);
+    assert!(cache.lock().await.is_empty());
+
+    let value = cache
+        .try_get_with(1, async { Ok::<_, Infallible>(42) })
+        .await
+        .unwrap();
+    assert_eq!(value, 42);
+    assert_eq!(cache.get(1).await, Some(42));
+}
+
+#[tokio::test]
+async fn test_no_cache_get_always_misses() {
+    let cache: NoCache<u8, u64> = NoCache::default();
+
+    cache
+        .try_get_with(1, async { Ok::<_, Infallible>(42) })
+        .await
+        .unwrap();
+    assert_eq!(cache.get(1).await, None);
+}
+
+/// A cache written before `SealCache::get` existed, relying on its default body.
+struct InitOnlyCache;
+
+#[async_trait]
+impl SealCache for InitOnlyCache {
+    type Key = u8;
+    type Value = u64;
+
+    async fn try_get_with<Fut, Error>(
+        &self,
+        _key: Self::Key,
+        init: Fut,
+    ) -> Result<Self::Value, Arc<Error>>
+    where
+        Fut: Future<Output = Result<Self::Value, Error>> + Send,
+        Error: Send + Sync + 'static,
+    {
+        init.await.map_err(Arc::new)
+    }
+}
+
+#[tokio::test]
+async fn test_default_get_misses() {
+    let cache = InitOnlyCache;
+
+    cache
+        .try_get_with(1, async { Ok::<_, Infallible>(42) })
+        .await
+        .unwrap();
+    assert_eq!(cache.get(1).await, None);
+}
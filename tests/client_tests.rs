@@ -13,11 +13,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg(feature = "native-sui-sdk")]
+
 use crate::utils::setup::setup;
 use anyhow::bail;
 use reqwest::Client;
 use seal_sdk_rs::base_client::{KeyServerConfig, ServerType};
+use seal_sdk_rs::builder::SealClientBuilder;
 use seal_sdk_rs::error::SealClientError;
+use seal_sdk_rs::fault_injection::{FaultInjectingHttpClient, HttpFault};
 use seal_sdk_rs::native_sui_sdk::client::seal_client::SealClient;
 use seal_sdk_rs::session_key::SessionKey;
 use std::collections::HashMap;
@@ -69,6 +73,7 @@ async fn test_encrypt_decrypt_bytes_single_server() -> anyhow::Result<()> {
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -134,6 +139,7 @@ async fn test_encrypt_decrypt_multiple_u64_single_server() -> anyhow::Result<()>
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -210,6 +216,7 @@ async fn test_encrypt_decrypt_multiple_bytes_single_server() -> anyhow::Result<(
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -291,6 +298,7 @@ async fn test_encrypt_decrypt_mutltiple_bytes_encrypt_one_by_one_single_server()
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -368,6 +376,7 @@ async fn test_encrypt_decrypt_mutltiple_bytes_decrypt_one_by_one_single_server()
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -442,6 +451,7 @@ async fn test_encrypt_decrypt_u64_single_server() -> anyhow::Result<()> {
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -503,6 +513,7 @@ async fn test_encrypt_decrypt_bytes_three_servers() -> anyhow::Result<()> {
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -577,6 +588,7 @@ async fn test_encrypt_decrypt_bytes_three_servers_threshold_two_one_crash() -> a
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -652,6 +664,7 @@ async fn test_encrypt_decrypt_bytes_three_servers_threshold_three_one_crash() ->
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -673,6 +686,79 @@ async fn test_encrypt_decrypt_bytes_three_servers_threshold_three_one_crash() ->
     Ok(())
 }
 
+#[tokio::test]
+async fn test_encrypt_decrypt_bytes_three_servers_threshold_two_one_injected_crash()
+-> anyhow::Result<()> {
+    let arc_setup = setup().await?;
+    let mut setup_guard = arc_setup.lock_unchecked();
+    let setup = setup_guard.deref_mut().as_mut().unwrap();
+
+    let sui_client = SuiClientBuilder::default().build(&setup.rpc_url).await?;
+
+    let crashed_server_url = setup.seal_instances[2].seal_server_url.clone();
+    let seal_client = SealClientBuilder::new()
+        .sui_client(sui_client)
+        .http_client(
+            FaultInjectingHttpClient::new(Client::new())
+                .with_fault(crashed_server_url, HttpFault::ConnectionError),
+        )
+        .build()?;
+
+    let data_to_encrypt = vec![0u8, 1, 2, 3];
+    let data_id = vec![6u8];
+
+    let seal_instances = setup
+        .seal_instances
+        .iter()
+        .take(3)
+        .map(|e| KeyServerConfig::new(e.key_server_id, None))
+        .collect();
+
+    let (encrypted, _) = seal_client
+        .encrypt_bytes(
+            setup.approve_package_id,
+            data_id.clone(),
+            2,
+            seal_instances,
+            data_to_encrypt.clone(),
+        )
+        .await?;
+
+    let mut approve_builder = ProgrammableTransactionBuilder::new();
+    let id_arg = approve_builder.pure(data_id)?;
+
+    _ = approve_builder.programmable_move_call(
+        setup.approve_package_id.into(),
+        Identifier::from_str("wildcard")?,
+        Identifier::from_str("seal_approve")?,
+        vec![],
+        vec![id_arg],
+    );
+
+    let ptb = approve_builder.finish();
+
+    let session_key = SessionKey::new(
+        setup.approve_package_id,
+        None,
+        1,
+        &mut setup.approve_package_deployer,
+    )
+    .await?;
+
+    let decrypted = seal_client
+        .decrypt_object_bytes(
+            &bcs::to_bytes(&encrypted)?,
+            ptb,
+            &session_key,
+            HashMap::new(),
+        )
+        .await?;
+
+    assert_eq!(decrypted, data_to_encrypt);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_encrypt_decrypt_bytes_committee() -> anyhow::Result<()> {
     let arc_setup = setup().await?;
@@ -717,6 +803,7 @@ async fn test_encrypt_decrypt_bytes_committee() -> anyhow::Result<()> {
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -783,6 +870,7 @@ async fn test_encrypt_decrypt_bytes_committee_wrong_aggregator_url() -> anyhow::
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -856,6 +944,7 @@ async fn test_encrypt_decrypt_bytes_committee_no_aggregator_url() -> anyhow::Res
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
@@ -928,6 +1017,7 @@ async fn test_encrypt_decrypt_bytes_independent_with_aggregator_url() -> anyhow:
 
     let session_key = SessionKey::new(
         setup.approve_package_id,
+        None,
         1,
         &mut setup.approve_package_deployer,
     )
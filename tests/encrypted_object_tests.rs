@@ -0,0 +1,199 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use base64::Engine;
+use seal_sdk_rs::crypto::{DemType, EncryptedObject, ServiceDescription};
+use seal_sdk_rs::error::EncryptedObjectError;
+use seal_sdk_rs::generic_types::ObjectID;
+use serde_json::Value;
+use std::str::FromStr;
+
+const ENCRYPTED_OBJECTS: &str = include_str!("vectors/ts_sdk/encrypted_objects.json");
+
+fn vectors() -> Vec<Value> {
+    serde_json::from_str(ENCRYPTED_OBJECTS).unwrap()
+}
+
+fn vector(name: &str) -> Value {
+    vectors()
+        .into_iter()
+        .find(|vector| vector["name"] == name)
+        .unwrap()
+}
+
+fn aad_len(vector: &Value) -> Option<usize> {
+    vector["ciphertext"]["aad"]
+        .as_str()
+        .map(|aad| hex::decode(aad).unwrap().len())
+}
+
+#[test]
+fn test_encrypted_object_parsing() -> anyhow::Result<()> {
+    for vector in vectors() {
+        let bytes = hex::decode(vector["bcs"].as_str().unwrap())?;
+
+        let from_bytes = EncryptedObject::from_bytes(&bytes)?;
+        let from_hex = EncryptedObject::from_hex(&format!("0x{}", hex::encode(&bytes)))?;
+        let from_base64 = EncryptedObject::from_base64(
+            &base64::engine::general_purpose::STANDARD.encode(&bytes),
+        )?;
+
+        assert_eq!(from_bytes.to_bytes()?, bytes);
+        assert_eq!(from_hex.to_bytes()?, bytes);
+        assert_eq!(from_base64.to_bytes()?, bytes);
+    }
+
+    assert!(matches!(
+        EncryptedObject::from_hex("0xzz"),
+        Err(EncryptedObjectError::HEXDeserialization(_))
+    ));
+    assert!(matches!(
+        EncryptedObject::from_base64("not base64!"),
+        Err(EncryptedObjectError::Base64(_))
+    ));
+    assert!(matches!(
+        EncryptedObject::from_bytes(&[0, 1, 2]),
+        Err(EncryptedObjectError::BCS(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_encrypted_object_describe() -> anyhow::Result<()> {
+    let vector = vector("aes_2_of_3");
+    let encrypted_object = EncryptedObject::from_hex(vector["bcs"].as_str().unwrap())?;
+    let package_id = ObjectID::from_str(vector["package_id"].as_str().unwrap())?;
+
+    let mut full_id = package_id.0.to_vec();
+    full_id.extend(hex::decode(vector["id"].as_str().unwrap())?);
+    assert_eq!(encrypted_object.full_id(), full_id);
+
+    let description = encrypted_object.describe();
+    assert_eq!(description.package_id, package_id);
+    assert_eq!(description.id, "0102030405");
+    assert_eq!(description.full_id, hex::encode(&full_id));
+    assert_eq!(description.threshold, 2);
+    assert_eq!(
+        description.services[1],
+        ServiceDescription {
+            object_id: ObjectID::from_str(vector["services"][1][0].as_str().unwrap())?,
+            share_index: 2,
+        }
+    );
+    assert_eq!(description.dem_type, DemType::Aes256Gcm);
+    assert_eq!(description.ciphertext_len, 32);
+    assert_eq!(description.aad_len, aad_len(&vector));
+
+    let report = description.to_string();
+    assert!(report.contains("threshold:  2 of 3"));
+    assert!(report.contains("AES-256-GCM, 32 bytes"));
+
+    let json = serde_json::to_value(&description)?;
+    assert_eq!(json["package_id"], vector["package_id"]);
+    assert_eq!(json["dem_type"], "Aes256Gcm");
+    assert_eq!(json["services"][0]["share_index"], 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_encrypted_object_describe_other_dems() -> anyhow::Result<()> {
+    let vector = vector("hmac_ctr");
+    let hmac = EncryptedObject::from_hex(vector["bcs"].as_str().unwrap())?;
+    assert_eq!(hmac.dem_type(), DemType::Hmac256Ctr);
+    assert_eq!(hmac.ciphertext_len(), 200);
+    assert_eq!(hmac.aad().map(<[u8]>::len), aad_len(&vector));
+    if let Some(aad_len) = aad_len(&vector) {
+        assert!(
+            hmac.describe()
+                .to_string()
+                .contains(&format!("{aad_len} bytes of AAD"))
+        );
+    }
+
+    let plain = EncryptedObject::from_hex(vector("plain")["bcs"].as_str().unwrap())?;
+    assert_eq!(plain.dem_type(), DemType::Plain);
+    assert_eq!(plain.ciphertext_len(), 0);
+    assert_eq!(plain.aad(), None);
+
+    Ok(())
+}
+
+fn aes_2_of_3() -> EncryptedObject {
+    EncryptedObject::from_hex(vector("aes_2_of_3")["bcs"].as_str().unwrap()).unwrap()
+}
+
+#[test]
+fn test_encrypted_object_validate_vectors() -> anyhow::Result<()> {
+    for vector in vectors() {
+        EncryptedObject::from_hex(vector["bcs"].as_str().unwrap())?.validate()?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_encrypted_object_validate_rejects_malformed_objects() {
+    let mut object = aes_2_of_3();
+    object.version = 1;
+    assert!(matches!(
+        object.validate(),
+        Err(EncryptedObjectError::UnsupportedVersion { version: 1 })
+    ));
+
+    let mut object = aes_2_of_3();
+    object.threshold = 0;
+    assert!(matches!(
+        object.validate(),
+        Err(EncryptedObjectError::InvalidThreshold {
+            threshold: 0,
+            services: 3
+        })
+    ));
+
+    let mut object = aes_2_of_3();
+    object.threshold = 4;
+    assert!(matches!(
+        object.validate(),
+        Err(EncryptedObjectError::InvalidThreshold {
+            threshold: 4,
+            services: 3
+        })
+    ));
+
+    let mut object = aes_2_of_3();
+    let extra_service = (ObjectID([0x44; 32]), 4);
+    object.services.push(extra_service);
+    assert!(matches!(
+        object.validate(),
+        Err(EncryptedObjectError::ShareCountMismatch {
+            shares: 3,
+            services: 4
+        })
+    ));
+
+    let mut object = aes_2_of_3();
+    object.services[2] = object.services[0];
+    let (duplicate_id, duplicate_index) = object.services[0];
+    match object.validate() {
+        Err(EncryptedObjectError::DuplicateService {
+            object_id,
+            share_index,
+        }) => {
+            assert_eq!(object_id, duplicate_id);
+            assert_eq!(share_index, duplicate_index);
+        }
+        other => panic!("unexpected validation result: {other:?}"),
+    }
+}
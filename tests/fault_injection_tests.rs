@@ -0,0 +1,149 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::utils::mock::{mock_servers, round_trip, server_url, sui_client};
+use seal_sdk_rs::builder::SealClientBuilder;
+use seal_sdk_rs::error::SealClientError;
+use seal_sdk_rs::fault_injection::{FaultInjectingHttpClient, FaultRule, HttpFault};
+use seal_sdk_rs::http_client::HttpClient;
+use seal_sdk_rs::mock_key_server::MockHttpClient;
+use seal_sdk_rs::retry::RetryPolicy;
+use std::collections::HashMap;
+use std::time::Duration;
+
+pub mod utils;
+
+/// Round trip with `threshold` out of three mock servers, decrypting through `faults`.
+async fn faulty_round_trip(
+    threshold: u8,
+    faults: impl FnOnce(
+        FaultInjectingHttpClient<MockHttpClient>,
+    ) -> FaultInjectingHttpClient<MockHttpClient>,
+) -> Result<Vec<u8>, SealClientError> {
+    let mock_servers = mock_servers(3);
+    let client = SealClientBuilder::new()
+        .sui_client(sui_client(&mock_servers))
+        .http_client(faults(FaultInjectingHttpClient::new(mock_servers.clone())))
+        .retry_policy(RetryPolicy::new(2).with_backoff(Duration::ZERO, Duration::ZERO))
+        .request_timeout(Duration::from_millis(200))
+        .build()?;
+
+    round_trip(&client, &mock_servers, threshold).await
+}
+
+fn assert_insufficient_keys(result: Result<Vec<u8>, SealClientError>, received: usize) {
+    match result {
+        Err(SealClientError::InsufficientKeys {
+            received: actual, ..
+        }) => assert_eq!(actual, received),
+        other => panic!("expected InsufficientKeys, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_threshold_two_with_one_crashed_server() -> anyhow::Result<()> {
+    let decrypted = faulty_round_trip(2, |client| {
+        client.with_fault(server_url(2), HttpFault::ConnectionError)
+    })
+    .await?;
+
+    assert_eq!(decrypted, b"secret");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_threshold_three_with_one_crashed_server() -> anyhow::Result<()> {
+    let result = faulty_round_trip(3, |client| {
+        client.with_fault(server_url(2), HttpFault::ConnectionError)
+    })
+    .await;
+
+    assert_insufficient_keys(result, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_hanging_server_times_out() -> anyhow::Result<()> {
+    let decrypted = faulty_round_trip(2, |client| {
+        client.with_fault(server_url(0), HttpFault::Hang)
+    })
+    .await?;
+
+    assert_eq!(decrypted, b"secret");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_error_statuses_and_corrupted_responses() -> anyhow::Result<()> {
+    let result = faulty_round_trip(2, |client| {
+        client
+            .with_fault(
+                server_url(0),
+                HttpFault::Status {
+                    status: 429,
+                    retry_after: Some(0),
+                },
+            )
+            .with_fault(server_url(1), HttpFault::TruncatedBody)
+            .with_fault(server_url(2), HttpFault::FlippedDecryptionKeys)
+    })
+    .await;
+
+    assert_insufficient_keys(result, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_latency_and_probabilistic_faults() -> anyhow::Result<()> {
+    let decrypted = faulty_round_trip(3, |client| {
+        client
+            .with_seed(7)
+            .with_fault(server_url(1), HttpFault::Latency(Duration::from_millis(20)))
+            .with_random_fault(0.0, HttpFault::ConnectionError)
+    })
+    .await?;
+
+    assert_eq!(decrypted, b"secret");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_status_fault_does_not_reach_the_server() -> anyhow::Result<()> {
+    let mock_servers = mock_servers(3);
+    let client = FaultInjectingHttpClient::new(mock_servers.clone()).with_rule(
+        FaultRule::new(HttpFault::Status {
+            status: 503,
+            retry_after: Some(3),
+        })
+        .for_url("/v1/fetch_key"),
+    );
+
+    let response = client
+        .post_with_headers(
+            &format!("{}/v1/fetch_key", server_url(0)),
+            HashMap::new(),
+            "{}",
+        )
+        .await?;
+
+    assert_eq!(response.status, 503);
+    assert_eq!(response.header("Retry-After"), Some("3"));
+    assert_eq!(mock_servers.servers()[0].fetch_key_requests(), 0);
+
+    Ok(())
+}
@@ -0,0 +1,262 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "graphql")]
+
+use async_trait::async_trait;
+use seal_sdk_rs::base_client::ServerType;
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::graphql::sui_client::{GraphQlSuiClient, GraphQlSuiClientError};
+use seal_sdk_rs::http_client::{HttpClient, PostResponse};
+use seal_sdk_rs::key_server_layout::KeyServerLayoutError;
+use seal_sdk_rs::sui_client::SuiClient;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+
+const KEY_SERVER_ID: &str = "0x6f4c8bead1dcbef4b880d1b845a70d820ee4da8b36805b97d93ef3e829ae8b55";
+const SEAL_PACKAGE: &str = "0x927a54e9ae803f82ebf480136a9bcff45101ccbe28b13f433c89f5181069d682";
+const GRAPHQL_URL: &str = "http://graphql.invalid/graphql";
+
+const KEY_SERVER_V1: &str = include_str!("fixtures/graphql/key_server_v1.json");
+const KEY_SERVER_V2_INDEPENDENT: &str =
+    include_str!("fixtures/graphql/key_server_v2_independent.json");
+const KEY_SERVER_V2_COMMITTEE: &str = include_str!("fixtures/graphql/key_server_v2_committee.json");
+const KEY_SERVER_WITHOUT_FIELDS: &str =
+    include_str!("fixtures/graphql/key_server_without_fields.json");
+
+/// Answers `KeyServerObjects` queries with recorded `object` selections keyed by address.
+///
+/// Addresses missing from the fixture resolve to `null`, as the service does for objects that
+/// do not exist.
+struct FixtureHttpClient {
+    objects: Map<String, Value>,
+}
+
+impl FixtureHttpClient {
+    fn new(fixture: &str) -> anyhow::Result<Self> {
+        let Value::Object(objects) = serde_json::from_str(fixture)? else {
+            anyhow::bail!("GraphQL fixtures map object addresses to selections");
+        };
+
+        Ok(Self { objects })
+    }
+
+    fn empty() -> Self {
+        Self {
+            objects: Map::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl HttpClient for FixtureHttpClient {
+    type PostError = String;
+
+    async fn post<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<PostResponse, Self::PostError> {
+        assert_eq!(url, GRAPHQL_URL);
+
+        let request: Value = serde_json::from_str(&body.to_string()).map_err(|e| e.to_string())?;
+        let variables = request["variables"]
+            .as_object()
+            .ok_or("Missing query variables")?;
+
+        let data = (0..variables.len())
+            .map(|index| {
+                let address = variables[&format!("id{index}")]
+                    .as_str()
+                    .unwrap_or_default();
+                let object = self.objects.get(address).cloned().unwrap_or(Value::Null);
+
+                (format!("object{index}"), object)
+            })
+            .collect::<Map<_, _>>();
+
+        Ok(PostResponse {
+            status: 200,
+            text: json!({ "data": data }).to_string(),
+        })
+    }
+}
+
+fn public_key(byte: u8) -> Vec<u8> {
+    vec![byte; 96]
+}
+
+#[tokio::test]
+async fn test_graphql_key_server_v2_independent() -> anyhow::Result<()> {
+    let sui_client = GraphQlSuiClient::new(
+        FixtureHttpClient::new(KEY_SERVER_V2_INDEPENDENT)?,
+        GRAPHQL_URL,
+    );
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    let info = sui_client.get_key_server_info(key_server_id.0).await?;
+
+    assert_eq!(info.object_id, key_server_id);
+    assert_eq!(info.package_id, SEAL_PACKAGE.parse()?);
+    assert_eq!(info.name, "mysten-testnet-1");
+    assert_eq!(info.public_key, hex::encode(public_key(7)));
+    assert!(matches!(
+        info.server_type,
+        ServerType::Independent { ref url } if url == "https://seal-key-server-testnet-1.mystenlabs.com"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_graphql_key_server_v2_committee() -> anyhow::Result<()> {
+    let sui_client = GraphQlSuiClient::new(
+        FixtureHttpClient::new(KEY_SERVER_V2_COMMITTEE)?,
+        GRAPHQL_URL,
+    );
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    let info = sui_client.get_key_server_info(key_server_id.0).await?;
+
+    assert_eq!(info.name, "committee");
+    assert_eq!(info.public_key, hex::encode(public_key(9)));
+
+    let ServerType::Committee {
+        version,
+        threshold,
+        partial_key_servers,
+    } = info.server_type
+    else {
+        panic!("Expected ServerType::Committee");
+    };
+
+    assert_eq!(version, 3);
+    assert_eq!(threshold, 2);
+    assert_eq!(partial_key_servers.len(), 3);
+    for (party_id, member) in partial_key_servers.iter().enumerate() {
+        assert_eq!(member.party_id as usize, party_id);
+        assert_eq!(member.name, format!("member-{party_id}"));
+        assert_eq!(member.url, format!("https://member-{party_id}.example.com"));
+        assert_eq!(member.partial_pk, public_key(party_id as u8));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_graphql_key_server_v1_fallback() -> anyhow::Result<()> {
+    let sui_client = GraphQlSuiClient::new(FixtureHttpClient::new(KEY_SERVER_V1)?, GRAPHQL_URL);
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    let info = sui_client.get_key_server_info(key_server_id.0).await?;
+
+    assert_eq!(info.name, "legacy");
+    assert_eq!(info.public_key, hex::encode(public_key(3)));
+    assert!(matches!(
+        info.server_type,
+        ServerType::Independent { ref url } if url == "https://legacy.example.com"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_graphql_key_server_without_dynamic_fields() -> anyhow::Result<()> {
+    let sui_client = GraphQlSuiClient::new(
+        FixtureHttpClient::new(KEY_SERVER_WITHOUT_FIELDS)?,
+        GRAPHQL_URL,
+    );
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    let result = sui_client.get_key_server_info(key_server_id.0).await;
+
+    assert!(matches!(
+        result,
+        Err(GraphQlSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::NoKeyServerVersion { object_id }
+        )) if object_id == key_server_id
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_graphql_key_server_object_not_found() -> anyhow::Result<()> {
+    let sui_client = GraphQlSuiClient::new(FixtureHttpClient::empty(), GRAPHQL_URL);
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    let result = sui_client.get_key_server_info(key_server_id.0).await;
+
+    assert!(matches!(
+        result,
+        Err(GraphQlSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::KeyServerNotFound { object_id }
+        )) if object_id == key_server_id
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_graphql_key_server_wrong_type() -> anyhow::Result<()> {
+    let mut http_client = FixtureHttpClient::new(KEY_SERVER_V2_INDEPENDENT)?;
+    // Keep the genuine `KeyServerV2` field but swap the parent for a package.
+    http_client
+        .objects
+        .insert(KEY_SERVER_ID.to_string(), json!({ "asMoveObject": null }));
+
+    let sui_client = GraphQlSuiClient::new(http_client, GRAPHQL_URL);
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    let result = sui_client.get_key_server_info(key_server_id.0).await;
+
+    assert!(matches!(
+        result,
+        Err(GraphQlSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::WrongKeyServerType { ref object_type, .. }
+        )) if object_type == "package"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_graphql_key_servers_batch() -> anyhow::Result<()> {
+    let sui_client = GraphQlSuiClient::new(
+        FixtureHttpClient::new(KEY_SERVER_V2_COMMITTEE)?,
+        GRAPHQL_URL,
+    );
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    let infos = sui_client
+        .get_key_servers_info(vec![key_server_id.0, key_server_id.0])
+        .await?;
+
+    assert_eq!(infos.len(), 2);
+    assert!(infos.iter().all(|info| info.name == "committee"));
+
+    let result = sui_client
+        .get_key_servers_info(vec![key_server_id.0, ObjectID([0x22; 32]).0])
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(GraphQlSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::KeyServerNotFound { object_id }
+        )) if object_id == ObjectID([0x22; 32])
+    ));
+
+    Ok(())
+}
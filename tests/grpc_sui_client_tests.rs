@@ -0,0 +1,221 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![cfg(feature = "grpc")]
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use seal_sdk_rs::base_client::{KeyServerInfo, ServerType};
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::grpc::sui_client::{GrpcSuiClientError, decode_get_object_response};
+use seal_sdk_rs::key_server_layout::{
+    KeyServerLayoutError, ObjectRead, resolve_key_server, resolve_key_servers,
+};
+use serde_json::{Map, Value};
+use sui_rpc::proto::sui::rpc::v2::{Bcs, GetObjectResponse, Object};
+
+const KEY_SERVER_ID: &str = "0x6f4c8bead1dcbef4b880d1b845a70d820ee4da8b36805b97d93ef3e829ae8b55";
+const SEAL_PACKAGE: &str = "0x927a54e9ae803f82ebf480136a9bcff45101ccbe28b13f433c89f5181069d682";
+
+// The GraphQL fixtures record the same type and BCS contents the gRPC service returns.
+const KEY_SERVER_V1: &str = include_str!("fixtures/graphql/key_server_v1.json");
+const KEY_SERVER_V2_INDEPENDENT: &str =
+    include_str!("fixtures/graphql/key_server_v2_independent.json");
+const KEY_SERVER_V2_COMMITTEE: &str = include_str!("fixtures/graphql/key_server_v2_committee.json");
+const KEY_SERVER_WITHOUT_FIELDS: &str =
+    include_str!("fixtures/graphql/key_server_without_fields.json");
+
+/// `GetObject` responses keyed by object id, as read with the `object_type,contents` mask.
+struct FixtureLedger {
+    objects: Map<String, Value>,
+}
+
+impl FixtureLedger {
+    fn new(fixture: &str) -> anyhow::Result<Self> {
+        let Value::Object(objects) = serde_json::from_str(fixture)? else {
+            anyhow::bail!("fixtures map object addresses to selections");
+        };
+
+        Ok(Self { objects })
+    }
+
+    fn response(&self, object_id: &ObjectID) -> Option<GetObjectResponse> {
+        let selection = self.objects.get(&object_id.to_string())?;
+
+        let mut object = Object::default();
+        match selection["asMoveObject"]["contents"].as_object() {
+            Some(contents) => {
+                let mut bcs = Bcs::default();
+                bcs.value = Some(
+                    STANDARD
+                        .decode(contents["bcs"].as_str().unwrap())
+                        .unwrap()
+                        .into(),
+                );
+
+                object.object_type = Some(contents["type"]["repr"].as_str().unwrap().to_string());
+                object.contents = Some(bcs);
+            }
+            None => object.object_type = Some("package".to_string()),
+        }
+
+        let mut response = GetObjectResponse::default();
+        response.object = Some(object);
+
+        Some(response)
+    }
+
+    /// Mirrors `GrpcSuiClient::get_objects`, a missing object being a `NotFound` status.
+    async fn get_objects(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> Result<Vec<ObjectRead>, GrpcSuiClientError> {
+        object_ids
+            .into_iter()
+            .map(|object_id| match self.response(&object_id) {
+                Some(response) => decode_get_object_response(object_id, response),
+                None => Ok(ObjectRead::NotFound),
+            })
+            .collect()
+    }
+
+    async fn key_server_info(&self) -> Result<KeyServerInfo, GrpcSuiClientError> {
+        resolve_key_server(KEY_SERVER_ID.parse().unwrap(), |object_ids| {
+            self.get_objects(object_ids)
+        })
+        .await
+    }
+}
+
+#[test]
+fn test_grpc_decode_get_object_response() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let ledger = FixtureLedger::new(KEY_SERVER_V2_INDEPENDENT)?;
+
+    let read = decode_get_object_response(key_server_id, ledger.response(&key_server_id).unwrap())?;
+
+    let ObjectRead::Found {
+        object_type,
+        contents,
+    } = read
+    else {
+        panic!("Expected ObjectRead::Found");
+    };
+    assert_eq!(
+        object_type,
+        format!("{SEAL_PACKAGE}::key_server::KeyServer")
+    );
+    assert_eq!(&contents[..32], &key_server_id.0);
+
+    let result = decode_get_object_response(key_server_id, GetObjectResponse::default());
+    assert!(matches!(
+        result,
+        Err(GrpcSuiClientError::NoObjectDataFromTheSuiRPC { object_id }) if object_id == key_server_id
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_grpc_key_server_v2_independent() -> anyhow::Result<()> {
+    let info = FixtureLedger::new(KEY_SERVER_V2_INDEPENDENT)?
+        .key_server_info()
+        .await?;
+
+    assert_eq!(info.object_id, KEY_SERVER_ID.parse()?);
+    assert_eq!(info.package_id, SEAL_PACKAGE.parse()?);
+    assert_eq!(info.name, "mysten-testnet-1");
+    assert_eq!(info.public_key, hex::encode([7u8; 96]));
+    assert!(matches!(
+        info.server_type,
+        ServerType::Independent { ref url } if url == "https://seal-key-server-testnet-1.mystenlabs.com"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_grpc_key_server_v2_committee() -> anyhow::Result<()> {
+    let info = FixtureLedger::new(KEY_SERVER_V2_COMMITTEE)?
+        .key_server_info()
+        .await?;
+
+    assert_eq!(info.name, "committee");
+    let ServerType::Committee {
+        threshold,
+        partial_key_servers,
+        ..
+    } = info.server_type
+    else {
+        panic!("Expected ServerType::Committee");
+    };
+    assert_eq!(threshold, 2);
+    assert_eq!(partial_key_servers.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_grpc_key_server_v1_fallback() -> anyhow::Result<()> {
+    let info = FixtureLedger::new(KEY_SERVER_V1)?.key_server_info().await?;
+
+    assert_eq!(info.name, "legacy");
+    assert!(matches!(
+        info.server_type,
+        ServerType::Independent { ref url } if url == "https://legacy.example.com"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_grpc_key_server_errors() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    let result = FixtureLedger::new(KEY_SERVER_WITHOUT_FIELDS)?
+        .key_server_info()
+        .await;
+    assert!(matches!(
+        result,
+        Err(GrpcSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::NoKeyServerVersion { object_id }
+        )) if object_id == key_server_id
+    ));
+
+    let mut ledger = FixtureLedger::new(KEY_SERVER_V2_INDEPENDENT)?;
+    ledger.objects.insert(
+        KEY_SERVER_ID.to_string(),
+        serde_json::json!({ "asMoveObject": null }),
+    );
+    let result = ledger.key_server_info().await;
+    assert!(matches!(
+        result,
+        Err(GrpcSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::WrongKeyServerType { ref object_type, .. }
+        )) if object_type == "package"
+    ));
+
+    let ledger = FixtureLedger::new(KEY_SERVER_V2_INDEPENDENT)?;
+    let result = resolve_key_servers(&[key_server_id, ObjectID([0x22; 32])], |object_ids| {
+        ledger.get_objects(object_ids)
+    })
+    .await;
+    assert!(matches!(
+        result,
+        Err(GrpcSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::KeyServerNotFound { object_id }
+        )) if object_id == ObjectID([0x22; 32])
+    ));
+
+    Ok(())
+}
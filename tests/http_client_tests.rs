@@ -0,0 +1,143 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use seal_sdk_rs::http_client::{GET_NOT_IMPLEMENTED, HttpClient, HttpResponse, PostResponse};
+use std::collections::HashMap;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Client relying on the default `get`, `post_with_headers` and `get_with_headers`
+/// implementations.
+struct PostOnlyHttpClient;
+
+#[async_trait]
+impl HttpClient for PostOnlyHttpClient {
+    type PostError = String;
+
+    async fn post<S: ToString + Send + Sync>(
+        &self,
+        _url: &str,
+        _headers: HashMap<String, String>,
+        body: S,
+    ) -> Result<PostResponse, Self::PostError> {
+        Ok(PostResponse {
+            status: 200,
+            text: body.to_string(),
+        })
+    }
+}
+
+/// Answers a single request with `response` and returns the URL to call.
+async fn start_stub_server(response: &'static str) -> anyhow::Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await?;
+
+        let mut buffer = Vec::new();
+        while !buffer.windows(4).any(|window| window == b"\r\n\r\n") {
+            let mut chunk = [0u8; 1024];
+            let read = stream.read(&mut chunk).await?;
+            anyhow::ensure!(read > 0, "connection closed before the end of the headers");
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+        assert!(buffer.starts_with(b"GET /v1/service?service_id=0x1 HTTP/1.1"));
+
+        stream.write_all(response.as_bytes()).await?;
+
+        Ok::<_, anyhow::Error>(())
+    });
+
+    Ok(url)
+}
+
+#[test]
+fn test_response_headers_are_case_insensitive() {
+    let response = HttpResponse::new(429, "Too Many Requests")
+        .with_header("Retry-After", "3")
+        .with_header("X-KeyServer-Version", "0.4.0");
+
+    assert!(!response.is_success());
+    assert_eq!(response.header("retry-after"), Some("3"));
+    assert_eq!(response.header("RETRY-AFTER"), Some("3"));
+    assert_eq!(response.header("x-keyserver-version"), Some("0.4.0"));
+    assert_eq!(response.header("request-id"), None);
+}
+
+#[tokio::test]
+async fn test_default_get_is_not_allowed() -> anyhow::Result<()> {
+    let response = PostOnlyHttpClient
+        .get("http://127.0.0.1:1/v1/service", HashMap::new())
+        .await
+        .map_err(anyhow::Error::msg)?;
+
+    assert_eq!(response.status, 405);
+    assert_eq!(response.text, GET_NOT_IMPLEMENTED);
+
+    let response = PostOnlyHttpClient
+        .get_with_headers("http://127.0.0.1:1/v1/service", HashMap::new())
+        .await
+        .map_err(anyhow::Error::msg)?;
+
+    assert_eq!(response.status, 405);
+    assert!(response.headers.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_default_post_with_headers_uses_post() -> anyhow::Result<()> {
+    let response = PostOnlyHttpClient
+        .post_with_headers("http://127.0.0.1:1/v1/fetch_key", HashMap::new(), "body")
+        .await
+        .map_err(anyhow::Error::msg)?;
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.text, "body");
+    assert!(response.headers.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reqwest_get_exposes_response_headers() -> anyhow::Result<()> {
+    let url = start_stub_server(
+        "HTTP/1.1 200 OK\r\n\
+         Content-Length: 2\r\n\
+         X-KeyServer-Version: 0.4.0\r\n\
+         Request-Id: 1b2c\r\n\
+         Vary: Origin\r\n\
+         Vary: Accept\r\n\
+         Connection: close\r\n\r\n\
+         {}",
+    )
+    .await?;
+
+    let response = HttpClient::get_with_headers(
+        &reqwest::Client::new(),
+        &format!("{url}/v1/service?service_id=0x1"),
+        HashMap::new(),
+    )
+    .await?;
+
+    assert!(response.is_success());
+    assert_eq!(response.text, "{}");
+    assert_eq!(response.header("X-KeyServer-Version"), Some("0.4.0"));
+    assert_eq!(response.header("request-id"), Some("1b2c"));
+    assert_eq!(response.header("vary"), Some("Origin, Accept"));
+
+    Ok(())
+}
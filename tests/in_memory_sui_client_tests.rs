@@ -0,0 +1,160 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use seal_sdk_rs::base_client::{KeyServerInfo, ServerType};
+use seal_sdk_rs::builder::SealClientBuilder;
+use seal_sdk_rs::error::SealClientError;
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::in_memory_sui_client::{InMemoryFault, InMemorySuiClient, InMemorySuiClientError};
+use seal_sdk_rs::sui_client::SuiClient;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+const FIXTURE_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/key_servers.json"
+);
+const INDEPENDENT_ID: ObjectID = ObjectID([1; 32]);
+const COMMITTEE_ID: ObjectID = ObjectID([2; 32]);
+
+fn committee(info: &KeyServerInfo, version: u32) -> KeyServerInfo {
+    KeyServerInfo {
+        server_type: ServerType::Committee {
+            version,
+            threshold: 2,
+            partial_key_servers: vec![],
+        },
+        ..info.clone()
+    }
+}
+
+fn committee_version(info: &KeyServerInfo) -> Option<u32> {
+    match info.server_type {
+        ServerType::Committee { version, .. } => Some(version),
+        ServerType::Independent { .. } => None,
+    }
+}
+
+#[tokio::test]
+async fn test_in_memory_sui_client_serves_fixtures() -> anyhow::Result<()> {
+    let sui_client = InMemorySuiClient::from_json_file(FIXTURE_PATH)?;
+
+    let infos = sui_client
+        .get_key_servers_info(vec![INDEPENDENT_ID.0, COMMITTEE_ID.0])
+        .await?;
+
+    assert_eq!(
+        infos
+            .iter()
+            .map(|info| info.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["independent", "committee"]
+    );
+    assert!(matches!(
+        &infos[0].server_type,
+        ServerType::Independent { url } if url == "https://key-server-1.example.com"
+    ));
+    assert_eq!(committee_version(&infos[1]), Some(1));
+    assert_eq!(sui_client.lookups(), 2);
+
+    let result = sui_client.get_key_server_info([3; 32]).await;
+    assert!(matches!(
+        result,
+        Err(InMemorySuiClientError::KeyServerNotFound { object_id }) if object_id == ObjectID([3; 32])
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_in_memory_sui_client_injects_faults() -> anyhow::Result<()> {
+    let sui_client = InMemorySuiClient::from_json_file(FIXTURE_PATH)?
+        .with_fault(INDEPENDENT_ID, InMemoryFault::NotFound)
+        .with_fault(COMMITTEE_ID, InMemoryFault::Error("rpc down".to_string()));
+
+    assert!(matches!(
+        sui_client.get_key_server_info(INDEPENDENT_ID.0).await,
+        Err(InMemorySuiClientError::KeyServerNotFound { .. })
+    ));
+    assert!(matches!(
+        sui_client.get_key_server_info(COMMITTEE_ID.0).await,
+        Err(InMemorySuiClientError::Injected { message, .. }) if message == "rpc down"
+    ));
+
+    sui_client.set_fault(INDEPENDENT_ID, None);
+    assert_eq!(
+        sui_client.get_key_server_info(INDEPENDENT_ID.0).await?.name,
+        "independent"
+    );
+
+    sui_client.set_fault(
+        COMMITTEE_ID,
+        Some(InMemoryFault::Latency(Duration::from_millis(50))),
+    );
+    let start = Instant::now();
+    assert_eq!(
+        sui_client.get_key_server_info(COMMITTEE_ID.0).await?.name,
+        "committee"
+    );
+    assert!(start.elapsed() >= Duration::from_millis(50));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_in_memory_sui_client_alternates_versions() -> anyhow::Result<()> {
+    let fixture = InMemorySuiClient::from_json_file(FIXTURE_PATH)?
+        .get_key_server_info(COMMITTEE_ID.0)
+        .await?;
+    let sui_client = InMemorySuiClient::new().with_key_server_versions(
+        COMMITTEE_ID,
+        [committee(&fixture, 1), committee(&fixture, 2)],
+    );
+
+    let mut versions = Vec::new();
+    for _ in 0..3 {
+        versions.push(committee_version(
+            &sui_client.get_key_server_info(COMMITTEE_ID.0).await?,
+        ));
+    }
+
+    assert_eq!(versions, vec![Some(1), Some(2), Some(1)]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_key_server_info_cache_avoids_lookups() -> anyhow::Result<()> {
+    let sui_client = Arc::new(InMemorySuiClient::from_json_file(FIXTURE_PATH)?);
+    let client = SealClientBuilder::new()
+        .sui_client(sui_client.clone())
+        .key_server_info_cache(Arc::new(Mutex::new(HashMap::new())))
+        .build()?;
+
+    client.get_key_server_info(INDEPENDENT_ID).await?;
+    client.get_key_server_info(INDEPENDENT_ID).await?;
+    assert_eq!(sui_client.lookups(), 1);
+
+    sui_client.remove_key_server(COMMITTEE_ID);
+    let result = client.get_key_server_info(COMMITTEE_ID).await;
+    assert!(matches!(
+        result,
+        Err(SealClientError::InMemorySuiClient(
+            InMemorySuiClientError::KeyServerNotFound { .. }
+        ))
+    ));
+
+    Ok(())
+}
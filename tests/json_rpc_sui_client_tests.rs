@@ -0,0 +1,562 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use seal_sdk_rs::base_client::ServerType;
+use seal_sdk_rs::error::SealClientError;
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::json_rpc::seal_client::JsonRpcSealClient;
+use seal_sdk_rs::json_rpc::sui_client::{JsonRpcSuiClient, JsonRpcSuiClientError};
+use seal_sdk_rs::key_server_layout::{
+    DynamicField, KEY_SERVER_V1, KEY_SERVER_V2, KeyServerLayoutError, KeyServerV1, KeyServerV2,
+    MAX_MULTI_GET_OBJECTS, MoveBag, MovePartialKeyServer, MoveServerType, key_server_field_id,
+};
+use seal_sdk_rs::sui_client::SuiClient;
+use serde::Serialize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const KEY_SERVER_ID: &str = "0x6f4c8bead1dcbef4b880d1b845a70d820ee4da8b36805b97d93ef3e829ae8b55";
+const SEAL_PACKAGE: &str = "0x927a54e9ae803f82ebf480136a9bcff45101ccbe28b13f433c89f5181069d682";
+
+/// Serves canned `sui_multiGetObjects` entries keyed by object id.
+async fn start_stub_rpc(objects: HashMap<String, Value>) -> anyhow::Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}", listener.local_addr()?);
+    let objects = Arc::new(objects);
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(handle_rpc_request(stream, objects.clone()));
+        }
+    });
+
+    Ok(url)
+}
+
+async fn handle_rpc_request(
+    mut stream: TcpStream,
+    objects: Arc<HashMap<String, Value>>,
+) -> anyhow::Result<()> {
+    let mut buffer = Vec::new();
+    let body_start = loop {
+        let mut chunk = [0u8; 4096];
+        let read = stream.read(&mut chunk).await?;
+        anyhow::ensure!(read > 0, "connection closed before the end of the headers");
+        buffer.extend_from_slice(&chunk[..read]);
+
+        if let Some(position) = buffer.windows(4).position(|window| window == b"\r\n\r\n") {
+            break position + 4;
+        }
+    };
+
+    let headers = String::from_utf8_lossy(&buffer[..body_start]).to_lowercase();
+    let content_length = headers
+        .lines()
+        .find_map(|line| line.strip_prefix("content-length:"))
+        .map(|value| value.trim().parse::<usize>())
+        .transpose()?
+        .unwrap_or_default();
+
+    while buffer.len() < body_start + content_length {
+        let mut chunk = [0u8; 4096];
+        let read = stream.read(&mut chunk).await?;
+        anyhow::ensure!(read > 0, "connection closed before the end of the body");
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+
+    let request: Value = serde_json::from_slice(&buffer[body_start..])?;
+    assert_eq!(request["method"], "sui_multiGetObjects");
+    assert_eq!(request["params"][1]["showType"], true);
+    assert_eq!(request["params"][1]["showBcs"], true);
+
+    let get_object = |object_id: &str| {
+        objects.get(object_id).cloned().unwrap_or_else(|| {
+            json!({
+                "error": {
+                    "code": "notExists",
+                    "object_id": object_id,
+                }
+            })
+        })
+    };
+
+    let object_ids = request["params"][0].as_array().cloned().unwrap_or_default();
+    let body = if object_ids.len() > MAX_MULTI_GET_OBJECTS {
+        // Fullnodes reject lookups above their limit.
+        json!({
+            "jsonrpc": "2.0",
+            "id": request["id"],
+            "error": {
+                "code": -32602,
+                "message": format!(
+                    "Number of object ids {} exceeds the maximum of {MAX_MULTI_GET_OBJECTS}",
+                    object_ids.len()
+                ),
+            }
+        })
+    } else {
+        let result = Value::Array(
+            object_ids
+                .iter()
+                .map(|object_id| get_object(object_id.as_str().unwrap_or_default()))
+                .collect(),
+        );
+
+        json!({ "jsonrpc": "2.0", "id": request["id"], "result": result })
+    }
+    .to_string();
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+
+    Ok(())
+}
+
+/// `sui_multiGetObjects` entry for a key server object of type `object_type`.
+fn key_server_object(key_server_id: ObjectID, object_type: &str) -> (String, Value) {
+    let response = json!({
+        "data": {
+            "objectId": key_server_id.to_string(),
+            "version": "12",
+            "digest": "8ZzMZMSV7MTMpFTaFzEyDGGzEBX4Qmrbf7uapRYSJJCP",
+            "type": object_type,
+            "bcs": {
+                "dataType": "moveObject",
+                "type": object_type,
+                "hasPublicTransfer": false,
+                "version": 12,
+                "bcsBytes": STANDARD.encode([0x00; 32]),
+            }
+        }
+    });
+
+    (key_server_id.to_string(), response)
+}
+
+fn seal_key_server_object(key_server_id: ObjectID) -> (String, Value) {
+    key_server_object(
+        key_server_id,
+        &format!("{SEAL_PACKAGE}::key_server::KeyServer"),
+    )
+}
+
+/// `sui_multiGetObjects` entry for the dynamic field holding `value` under `version`.
+fn dynamic_field_object<V: Serialize>(
+    key_server_id: ObjectID,
+    version: u64,
+    value_type: &str,
+    value: V,
+) -> anyhow::Result<(String, Value)> {
+    let field_id = key_server_field_id(key_server_id, version);
+    let field = DynamicField {
+        id: field_id.0,
+        name: version,
+        value,
+    };
+
+    let bcs_bytes = bcs::to_bytes(&field)?;
+
+    let field_type =
+        format!("0x2::dynamic_field::Field<u64, {SEAL_PACKAGE}::key_server::{value_type}>");
+    let response = json!({
+        "data": {
+            "objectId": field_id.to_string(),
+            "version": "12",
+            "digest": "8ZzMZMSV7MTMpFTaFzEyDGGzEBX4Qmrbf7uapRYSJJCP",
+            "type": field_type,
+            "bcs": {
+                "dataType": "moveObject",
+                "type": field_type,
+                "hasPublicTransfer": false,
+                "version": 12,
+                "bcsBytes": STANDARD.encode(bcs_bytes),
+            }
+        }
+    });
+
+    Ok((field_id.to_string(), response))
+}
+
+fn extra_fields_bag() -> MoveBag {
+    MoveBag {
+        id: [0x5a; 32],
+        size: 0,
+    }
+}
+
+fn public_key(byte: u8) -> Vec<u8> {
+    vec![byte; 96]
+}
+
+#[tokio::test]
+async fn test_json_rpc_key_server_v2_independent() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let rpc_url = start_stub_rpc(HashMap::from([
+        seal_key_server_object(key_server_id),
+        dynamic_field_object(
+            key_server_id,
+            KEY_SERVER_V2,
+            "KeyServerV2",
+            KeyServerV2 {
+                name: "mysten-testnet-1".to_string(),
+                key_type: 0,
+                pk: public_key(7),
+                server_type: MoveServerType::Independent {
+                    url: "https://seal-key-server-testnet-1.mystenlabs.com".to_string(),
+                },
+                extra_fields: extra_fields_bag(),
+            },
+        )?,
+    ]))
+    .await?;
+
+    let sui_client = JsonRpcSuiClient::new(reqwest::Client::new(), rpc_url);
+
+    let info = sui_client.get_key_server_info(key_server_id.0).await?;
+
+    assert_eq!(info.object_id, key_server_id);
+    assert_eq!(info.package_id, SEAL_PACKAGE.parse()?);
+    assert_eq!(info.name, "mysten-testnet-1");
+    assert_eq!(info.public_key, hex::encode(public_key(7)));
+    assert!(matches!(
+        info.server_type,
+        ServerType::Independent { ref url } if url == "https://seal-key-server-testnet-1.mystenlabs.com"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_rpc_key_server_v2_committee() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let rpc_url = start_stub_rpc(HashMap::from([
+        seal_key_server_object(key_server_id),
+        dynamic_field_object(
+            key_server_id,
+            KEY_SERVER_V2,
+            "KeyServerV2",
+            KeyServerV2 {
+                name: "committee".to_string(),
+                key_type: 0,
+                pk: public_key(9),
+                server_type: MoveServerType::Committee {
+                    version: 3,
+                    threshold: 2,
+                    partial_key_servers: (0..3)
+                        .map(|party_id| MovePartialKeyServer {
+                            name: format!("member-{party_id}"),
+                            url: format!("https://member-{party_id}.example.com"),
+                            partial_pk: public_key(party_id as u8),
+                            party_id,
+                        })
+                        .collect(),
+                },
+                extra_fields: extra_fields_bag(),
+            },
+        )?,
+    ]))
+    .await?;
+
+    let sui_client = JsonRpcSuiClient::new(reqwest::Client::new(), rpc_url);
+
+    let info = sui_client.get_key_server_info(key_server_id.0).await?;
+
+    let ServerType::Committee {
+        version,
+        threshold,
+        partial_key_servers,
+    } = info.server_type
+    else {
+        panic!("Expected ServerType::Committee");
+    };
+
+    assert_eq!(version, 3);
+    assert_eq!(threshold, 2);
+    assert_eq!(partial_key_servers.len(), 3);
+    for (party_id, member) in partial_key_servers.iter().enumerate() {
+        assert_eq!(member.party_id as usize, party_id);
+        assert_eq!(member.name, format!("member-{party_id}"));
+        assert_eq!(member.url, format!("https://member-{party_id}.example.com"));
+        assert_eq!(member.partial_pk, public_key(party_id as u8));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_rpc_key_server_v1_fallback() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let rpc_url = start_stub_rpc(HashMap::from([
+        seal_key_server_object(key_server_id),
+        dynamic_field_object(
+            key_server_id,
+            KEY_SERVER_V1,
+            "KeyServerV1",
+            KeyServerV1 {
+                name: "legacy".to_string(),
+                url: "https://legacy.example.com".to_string(),
+                key_type: 0,
+                pk: public_key(3),
+            },
+        )?,
+    ]))
+    .await?;
+
+    let sui_client = JsonRpcSuiClient::new(reqwest::Client::new(), rpc_url);
+
+    let info = sui_client.get_key_server_info(key_server_id.0).await?;
+
+    assert_eq!(info.name, "legacy");
+    assert_eq!(info.public_key, hex::encode(public_key(3)));
+    assert!(matches!(
+        info.server_type,
+        ServerType::Independent { ref url } if url == "https://legacy.example.com"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_rpc_key_server_not_found() -> anyhow::Result<()> {
+    let rpc_url = start_stub_rpc(HashMap::new()).await?;
+
+    let sui_client = JsonRpcSuiClient::new(reqwest::Client::new(), rpc_url);
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    let result = sui_client.get_key_server_info(key_server_id.0).await;
+
+    assert!(matches!(
+        result,
+        Err(JsonRpcSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::KeyServerNotFound { object_id }
+        )) if object_id == key_server_id
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_rpc_key_server_deleted() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let rpc_url = start_stub_rpc(HashMap::from([(
+        key_server_id.to_string(),
+        json!({
+            "error": {
+                "code": "deleted",
+                "object_id": key_server_id.to_string(),
+                "version": 13,
+                "digest": "8ZzMZMSV7MTMpFTaFzEyDGGzEBX4Qmrbf7uapRYSJJCP",
+            }
+        }),
+    )]))
+    .await?;
+
+    let sui_client = JsonRpcSuiClient::new(reqwest::Client::new(), rpc_url);
+
+    let result = sui_client.get_key_server_info(key_server_id.0).await;
+
+    assert!(matches!(
+        result,
+        Err(JsonRpcSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::KeyServerDeleted { object_id }
+        )) if object_id == key_server_id
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_rpc_key_server_wrong_type() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    // A lookalike object carrying a valid `KeyServerV2` field under an unrelated type.
+    let rpc_url = start_stub_rpc(HashMap::from([
+        key_server_object(key_server_id, "0xbad::key_server::Impostor"),
+        dynamic_field_object(
+            key_server_id,
+            KEY_SERVER_V2,
+            "KeyServerV2",
+            KeyServerV2 {
+                name: "impostor".to_string(),
+                key_type: 0,
+                pk: public_key(1),
+                server_type: MoveServerType::Independent {
+                    url: "https://impostor.example.com".to_string(),
+                },
+                extra_fields: extra_fields_bag(),
+            },
+        )?,
+    ]))
+    .await?;
+
+    let sui_client = JsonRpcSuiClient::new(reqwest::Client::new(), rpc_url);
+
+    let result = sui_client.get_key_server_info(key_server_id.0).await;
+
+    assert!(matches!(
+        result,
+        Err(JsonRpcSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::WrongKeyServerType { ref object_type, .. }
+        )) if object_type == "0xbad::key_server::Impostor"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_rpc_key_servers_batch() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let legacy_key_server_id = ObjectID([0x11; 32]);
+
+    let rpc_url = start_stub_rpc(HashMap::from([
+        seal_key_server_object(key_server_id),
+        seal_key_server_object(legacy_key_server_id),
+        dynamic_field_object(
+            key_server_id,
+            KEY_SERVER_V2,
+            "KeyServerV2",
+            KeyServerV2 {
+                name: "mysten-testnet-1".to_string(),
+                key_type: 0,
+                pk: public_key(7),
+                server_type: MoveServerType::Independent {
+                    url: "https://seal-key-server-testnet-1.mystenlabs.com".to_string(),
+                },
+                extra_fields: extra_fields_bag(),
+            },
+        )?,
+        dynamic_field_object(
+            legacy_key_server_id,
+            KEY_SERVER_V1,
+            "KeyServerV1",
+            KeyServerV1 {
+                name: "legacy".to_string(),
+                url: "https://legacy.example.com".to_string(),
+                key_type: 0,
+                pk: public_key(3),
+            },
+        )?,
+    ]))
+    .await?;
+
+    let sui_client = JsonRpcSuiClient::new(reqwest::Client::new(), rpc_url);
+
+    let infos = sui_client
+        .get_key_servers_info(vec![legacy_key_server_id.0, key_server_id.0])
+        .await?;
+
+    assert_eq!(infos.len(), 2);
+    assert_eq!(infos[0].object_id, legacy_key_server_id);
+    assert_eq!(infos[0].name, "legacy");
+    assert_eq!(infos[1].object_id, key_server_id);
+    assert_eq!(infos[1].name, "mysten-testnet-1");
+
+    let result = sui_client
+        .get_key_servers_info(vec![key_server_id.0, ObjectID([0x22; 32]).0])
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(JsonRpcSuiClientError::KeyServerLayout(
+            KeyServerLayoutError::KeyServerNotFound { object_id }
+        )) if object_id == ObjectID([0x22; 32])
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_rpc_key_servers_batch_above_multi_get_limit() -> anyhow::Result<()> {
+    // Each key server needs two objects, so this batch spans several `sui_multiGetObjects` calls.
+    let key_server_ids = (0..30u8)
+        .map(|index| ObjectID([index + 1; 32]))
+        .collect::<Vec<_>>();
+
+    let mut objects = HashMap::new();
+    for (index, key_server_id) in key_server_ids.iter().enumerate() {
+        objects.extend([
+            seal_key_server_object(*key_server_id),
+            dynamic_field_object(
+                *key_server_id,
+                KEY_SERVER_V2,
+                "KeyServerV2",
+                KeyServerV2 {
+                    name: format!("server-{index}"),
+                    key_type: 0,
+                    pk: public_key(index as u8),
+                    server_type: MoveServerType::Independent {
+                        url: format!("https://server-{index}.example.com"),
+                    },
+                    extra_fields: extra_fields_bag(),
+                },
+            )?,
+        ]);
+    }
+    let rpc_url = start_stub_rpc(objects).await?;
+
+    let sui_client = JsonRpcSuiClient::new(reqwest::Client::new(), rpc_url);
+
+    let infos = sui_client
+        .get_key_servers_info(key_server_ids.iter().map(|id| id.0).collect())
+        .await?;
+
+    assert_eq!(infos.len(), key_server_ids.len());
+    for (index, (info, key_server_id)) in infos.iter().zip(&key_server_ids).enumerate() {
+        assert_eq!(info.object_id, *key_server_id);
+        assert_eq!(info.name, format!("server-{index}"));
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_rpc_seal_client_uses_the_given_http_client() -> anyhow::Result<()> {
+    // Accepts connections but never answers, so only the client timeout ends the call.
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let rpc_url = format!("http://{}", listener.local_addr()?);
+    tokio::spawn(async move {
+        let mut connections = Vec::new();
+        while let Ok((stream, _)) = listener.accept().await {
+            connections.push(stream);
+        }
+    });
+
+    let http_client = reqwest::Client::builder()
+        .timeout(Duration::from_millis(200))
+        .build()?;
+    let seal_client = JsonRpcSealClient::new_with_http_client(rpc_url, http_client);
+
+    let started = Instant::now();
+    let result = seal_client
+        .get_key_server_info(KEY_SERVER_ID.parse::<ObjectID>()?)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::JsonRpcSuiClient(
+            JsonRpcSuiClientError::Http { .. }
+        ))
+    ));
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    Ok(())
+}
@@ -0,0 +1,213 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::utils::static_sui_client::{SEAL_PACKAGE, key_server_info};
+use async_trait::async_trait;
+use seal_sdk_rs::base_client::{BaseSealClient, DerivedKeys, KeyServerConfig, KeyServerInfo};
+use seal_sdk_rs::cache::NoCache;
+use seal_sdk_rs::cache_key::{DerivedKeyCacheKey, KeyServerInfoCacheKey};
+use seal_sdk_rs::error::SealClientError;
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::json_rpc::sui_client::JsonRpcSuiClientError;
+use seal_sdk_rs::sui_client::SuiClient;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub mod utils;
+
+/// Sui client counting single and batched lookups.
+#[derive(Default)]
+struct CountingSuiClient {
+    single_calls: AtomicUsize,
+    batches: Mutex<Vec<usize>>,
+}
+
+#[async_trait]
+impl SuiClient for CountingSuiClient {
+    type Error = JsonRpcSuiClientError;
+
+    async fn get_key_server_info(
+        &self,
+        key_server_id: [u8; 32],
+    ) -> Result<KeyServerInfo, Self::Error> {
+        self.single_calls.fetch_add(1, Ordering::SeqCst);
+
+        Ok(key_server_info(ObjectID(key_server_id)))
+    }
+
+    async fn get_key_servers_info(
+        &self,
+        key_server_ids: Vec<[u8; 32]>,
+    ) -> Result<Vec<KeyServerInfo>, Self::Error> {
+        self.batches.lock().unwrap().push(key_server_ids.len());
+
+        Ok(key_server_ids
+            .into_iter()
+            .map(|key_server_id| key_server_info(ObjectID(key_server_id)))
+            .collect())
+    }
+}
+
+/// Sui client relying on the default batch implementation.
+#[derive(Default)]
+struct SingleLookupSuiClient {
+    calls: AtomicUsize,
+}
+
+#[async_trait]
+impl SuiClient for SingleLookupSuiClient {
+    type Error = JsonRpcSuiClientError;
+
+    async fn get_key_server_info(
+        &self,
+        key_server_id: [u8; 32],
+    ) -> Result<KeyServerInfo, Self::Error> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+
+        Ok(key_server_info(ObjectID(key_server_id)))
+    }
+}
+
+fn key_server_configs(ids: &[u8]) -> Vec<KeyServerConfig> {
+    ids.iter()
+        .map(|&id| KeyServerConfig::new(ObjectID([id; 32]), None))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_key_server_info_is_resolved_in_one_batch() -> anyhow::Result<()> {
+    let sui_client = Arc::new(CountingSuiClient::default());
+    let client = BaseSealClient::new_custom(
+        Arc::new(tokio::sync::Mutex::new(HashMap::<
+            KeyServerInfoCacheKey,
+            KeyServerInfo,
+        >::new())),
+        NoCache::<DerivedKeyCacheKey, DerivedKeys>::default(),
+        SharedSuiClient(sui_client.clone()),
+        reqwest::Client::new(),
+    );
+
+    let infos = client
+        .key_server_info(key_server_configs(&[3, 1, 2]))
+        .await?;
+
+    assert_eq!(
+        infos.iter().map(|info| info.object_id).collect::<Vec<_>>(),
+        vec![ObjectID([3; 32]), ObjectID([1; 32]), ObjectID([2; 32])]
+    );
+    assert_eq!(infos[0].name, "server-3");
+    assert_eq!(*sui_client.batches.lock().unwrap(), vec![3]);
+    assert_eq!(sui_client.single_calls.load(Ordering::SeqCst), 0);
+
+    // Cached servers are not looked up again, only the new one goes into the batch.
+    let infos = client
+        .key_server_info(key_server_configs(&[1, 4, 2]))
+        .await?;
+
+    assert_eq!(infos[1].object_id, ObjectID([4; 32]));
+    assert_eq!(*sui_client.batches.lock().unwrap(), vec![3, 1]);
+
+    client.key_server_info(key_server_configs(&[4, 3])).await?;
+
+    assert_eq!(*sui_client.batches.lock().unwrap(), vec![3, 1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_default_batch_implementation_uses_single_lookups() -> anyhow::Result<()> {
+    let sui_client = SingleLookupSuiClient::default();
+
+    let infos = sui_client
+        .get_key_servers_info(vec![[5; 32], [6; 32], [7; 32]])
+        .await?;
+
+    assert_eq!(
+        infos.iter().map(|info| info.object_id).collect::<Vec<_>>(),
+        vec![ObjectID([5; 32]), ObjectID([6; 32]), ObjectID([7; 32])]
+    );
+    assert_eq!(sui_client.calls.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+/// Lets the test keep a handle on the counters while the client owns the Sui client.
+struct SharedSuiClient(Arc<CountingSuiClient>);
+
+#[async_trait]
+impl SuiClient for SharedSuiClient {
+    type Error = JsonRpcSuiClientError;
+
+    async fn get_key_server_info(
+        &self,
+        key_server_id: [u8; 32],
+    ) -> Result<KeyServerInfo, Self::Error> {
+        self.0.get_key_server_info(key_server_id).await
+    }
+
+    async fn get_key_servers_info(
+        &self,
+        key_server_ids: Vec<[u8; 32]>,
+    ) -> Result<Vec<KeyServerInfo>, Self::Error> {
+        self.0.get_key_servers_info(key_server_ids).await
+    }
+}
+
+#[tokio::test]
+async fn test_untrusted_key_server_package_is_rejected() -> anyhow::Result<()> {
+    let client = |trusted_package: ObjectID| {
+        BaseSealClient::new_custom(
+            Arc::new(tokio::sync::Mutex::new(HashMap::<
+                KeyServerInfoCacheKey,
+                KeyServerInfo,
+            >::new())),
+            NoCache::<DerivedKeyCacheKey, DerivedKeys>::default(),
+            CountingSuiClient::default(),
+            reqwest::Client::new(),
+        )
+        .with_trusted_seal_packages([trusted_package])
+    };
+
+    let trusting = client(SEAL_PACKAGE);
+    assert_eq!(trusting.trusted_seal_packages(), Some(&[SEAL_PACKAGE][..]));
+    assert_eq!(
+        trusting
+            .key_server_info(key_server_configs(&[1, 2]))
+            .await?
+            .len(),
+        2
+    );
+
+    let other_package = ObjectID([0x77; 32]);
+    let result = client(other_package)
+        .key_server_info(key_server_configs(&[1, 2]))
+        .await;
+    assert!(matches!(
+        result,
+        Err(SealClientError::UntrustedKeyServerPackage { object_id, package_id })
+            if object_id == ObjectID([1; 32]) && package_id == SEAL_PACKAGE
+    ));
+
+    let result = client(other_package)
+        .get_key_server_info(ObjectID([3; 32]))
+        .await;
+    assert!(matches!(
+        result,
+        Err(SealClientError::UntrustedKeyServerPackage { .. })
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,409 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use seal_sdk_rs::base_client::ServerType;
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::key_server_layout::{
+    DynamicField, KEY_SERVER_V1, KEY_SERVER_V2, KeyServerLayout, KeyServerLayoutError, KeyServerV1,
+    KeyServerV2, MoveBag, MovePartialKeyServer, MoveServerType, ObjectRead, decode_key_server,
+    decode_key_server_field, key_server_field_id, key_server_package_id, resolve_key_servers,
+};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+const KEY_SERVER_ID: &str = "0x6f4c8bead1dcbef4b880d1b845a70d820ee4da8b36805b97d93ef3e829ae8b55";
+const SEAL_PACKAGE: &str = "0x927a54e9ae803f82ebf480136a9bcff45101ccbe28b13f433c89f5181069d682";
+
+fn extra_fields_bag() -> MoveBag {
+    MoveBag {
+        id: [0x5a; 32],
+        size: 0,
+    }
+}
+
+fn committee_key_server() -> KeyServerV2 {
+    KeyServerV2 {
+        name: "committee".to_string(),
+        key_type: 0,
+        pk: vec![9; 96],
+        server_type: MoveServerType::Committee {
+            version: 3,
+            threshold: 2,
+            partial_key_servers: (0..3)
+                .map(|party_id| MovePartialKeyServer {
+                    name: format!("member-{party_id}"),
+                    url: format!("https://member-{party_id}.example.com"),
+                    partial_pk: vec![party_id as u8; 96],
+                    party_id,
+                })
+                .collect(),
+        },
+        extra_fields: extra_fields_bag(),
+    }
+}
+
+#[test]
+fn test_key_server_field_id() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    assert_eq!(
+        key_server_field_id(key_server_id, KEY_SERVER_V1),
+        "0x4cf9427d9f48441de3f67d8f68c74ff9ae27a3cbba47d17b4a22cccac8a615bb".parse()?
+    );
+    assert_eq!(
+        key_server_field_id(key_server_id, KEY_SERVER_V2),
+        "0xfadb71852ded30cc02ad4a63ce188d3e2e80cf1e6b2169a8f24d55821def8248".parse()?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_key_server_v2_round_trip() -> anyhow::Result<()> {
+    let key_server = committee_key_server();
+
+    assert_eq!(
+        KeyServerV2::from_bcs(&bcs::to_bytes(&key_server)?)?,
+        key_server
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_key_server_rejects_trailing_bytes() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+
+    let mut bytes = bcs::to_bytes(&committee_key_server())?;
+    bytes.push(0);
+    assert!(matches!(
+        KeyServerV2::from_bcs(&bytes),
+        Err(KeyServerLayoutError::BCS(_))
+    ));
+
+    let field = DynamicField {
+        id: key_server_field_id(key_server_id, KEY_SERVER_V2).0,
+        name: KEY_SERVER_V2,
+        value: committee_key_server(),
+    };
+    let mut bytes = bcs::to_bytes(&field)?;
+    bytes.extend([0xde, 0xad, 0xbe, 0xef]);
+    assert!(matches!(
+        KeyServerV2::from_field_bcs(&bytes),
+        Err(KeyServerLayoutError::BCS(_))
+    ));
+
+    // A V2 value that is missing its `extra_fields` bag is rejected too.
+    let mut bytes = bcs::to_bytes(&committee_key_server())?;
+    bytes.truncate(bytes.len() - 40);
+    assert!(KeyServerV2::from_bcs(&bytes).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_key_server_v2_field_contents() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let field = DynamicField {
+        id: key_server_field_id(key_server_id, KEY_SERVER_V2).0,
+        name: KEY_SERVER_V2,
+        value: committee_key_server(),
+    };
+
+    let info = KeyServerV2::from_field_bcs(&bcs::to_bytes(&field)?)?
+        .into_key_server_info(key_server_id, SEAL_PACKAGE.parse()?);
+
+    assert_eq!(info.object_id, key_server_id);
+    assert_eq!(info.name, "committee");
+    assert_eq!(info.public_key, hex::encode([9; 96]));
+
+    let ServerType::Committee {
+        version,
+        threshold,
+        partial_key_servers,
+    } = info.server_type
+    else {
+        panic!("Expected ServerType::Committee");
+    };
+
+    assert_eq!(version, 3);
+    assert_eq!(threshold, 2);
+    assert_eq!(partial_key_servers.len(), 3);
+    for (party_id, member) in partial_key_servers.iter().enumerate() {
+        assert_eq!(member.party_id as usize, party_id);
+        assert_eq!(member.url, format!("https://member-{party_id}.example.com"));
+        assert_eq!(member.partial_pk, vec![party_id as u8; 96]);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_key_server_v1_field_contents() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let field = DynamicField {
+        id: key_server_field_id(key_server_id, KEY_SERVER_V1).0,
+        name: KEY_SERVER_V1,
+        value: KeyServerV1 {
+            name: "legacy".to_string(),
+            url: "https://legacy.example.com".to_string(),
+            key_type: 0,
+            pk: vec![3; 96],
+        },
+    };
+
+    let info = KeyServerV1::from_field_bcs(&bcs::to_bytes(&field)?)?
+        .into_key_server_info(key_server_id, SEAL_PACKAGE.parse()?);
+
+    assert_eq!(info.name, "legacy");
+    assert_eq!(info.public_key, hex::encode([3; 96]));
+    assert!(matches!(
+        info.server_type,
+        ServerType::Independent { ref url } if url == "https://legacy.example.com"
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_key_server_by_version() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let bytes = bcs::to_bytes(&committee_key_server())?;
+
+    let info = decode_key_server(key_server_id, SEAL_PACKAGE.parse()?, KEY_SERVER_V2, &bytes)?;
+    assert_eq!(info.name, "committee");
+
+    assert!(matches!(
+        decode_key_server(key_server_id, SEAL_PACKAGE.parse()?, 3, &bytes),
+        Err(KeyServerLayoutError::UnsupportedVersion { version: 3 })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_key_server_field_rejects_other_version() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let field = DynamicField {
+        id: key_server_field_id(key_server_id, KEY_SERVER_V1).0,
+        name: KEY_SERVER_V1,
+        value: committee_key_server(),
+    };
+
+    let result = decode_key_server_field(
+        key_server_id,
+        SEAL_PACKAGE.parse()?,
+        KEY_SERVER_V2,
+        &bcs::to_bytes(&field)?,
+    );
+
+    assert!(matches!(
+        result,
+        Err(KeyServerLayoutError::UnexpectedFieldName {
+            expected: KEY_SERVER_V2,
+            found: KEY_SERVER_V1,
+        })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_key_server_package_id() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let found = |object_type: &str| ObjectRead::Found {
+        object_type: object_type.to_string(),
+        contents: vec![],
+    };
+
+    assert_eq!(
+        key_server_package_id(
+            key_server_id,
+            &found(&format!("{SEAL_PACKAGE}::key_server::KeyServer"))
+        )?,
+        SEAL_PACKAGE.parse()?
+    );
+    // Short address form, as printed by some SDKs.
+    assert_eq!(
+        key_server_package_id(key_server_id, &found("0x2a::key_server::KeyServer"))?,
+        ObjectID({
+            let mut id = [0; 32];
+            id[31] = 0x2a;
+            id
+        })
+    );
+
+    for object_type in [
+        "0x2::coin::Coin<0x2::sui::SUI>",
+        "package",
+        &format!("{SEAL_PACKAGE}::key_server::KeyServerV2"),
+        &format!("{SEAL_PACKAGE}::other::KeyServer"),
+    ] {
+        assert!(matches!(
+            key_server_package_id(key_server_id, &found(object_type)),
+            Err(KeyServerLayoutError::WrongKeyServerType { .. })
+        ));
+    }
+
+    assert!(matches!(
+        key_server_package_id(key_server_id, &ObjectRead::NotFound),
+        Err(KeyServerLayoutError::KeyServerNotFound { .. })
+    ));
+    assert!(matches!(
+        key_server_package_id(key_server_id, &ObjectRead::Deleted),
+        Err(KeyServerLayoutError::KeyServerDeleted { .. })
+    ));
+
+    Ok(())
+}
+
+/// Object store standing in for a Sui backend, recording the size of each multi-get.
+struct ObjectStore {
+    objects: HashMap<ObjectID, ObjectRead>,
+    calls: Mutex<Vec<usize>>,
+}
+
+impl ObjectStore {
+    fn new(objects: impl IntoIterator<Item = (ObjectID, ObjectRead)>) -> Self {
+        Self {
+            objects: objects.into_iter().collect(),
+            calls: Mutex::new(vec![]),
+        }
+    }
+
+    async fn fetch(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> Result<Vec<ObjectRead>, KeyServerLayoutError> {
+        self.calls.lock().unwrap().push(object_ids.len());
+
+        Ok(object_ids
+            .iter()
+            .map(|object_id| {
+                self.objects
+                    .get(object_id)
+                    .cloned()
+                    .unwrap_or(ObjectRead::NotFound)
+            })
+            .collect())
+    }
+}
+
+fn key_server_object() -> ObjectRead {
+    ObjectRead::Found {
+        object_type: format!("{SEAL_PACKAGE}::key_server::KeyServer"),
+        contents: vec![],
+    }
+}
+
+fn field_object<V: serde::Serialize>(
+    key_server_id: ObjectID,
+    version: u64,
+    value: V,
+) -> anyhow::Result<(ObjectID, ObjectRead)> {
+    let field_id = key_server_field_id(key_server_id, version);
+    let contents = bcs::to_bytes(&DynamicField {
+        id: field_id.0,
+        name: version,
+        value,
+    })?;
+
+    Ok((
+        field_id,
+        ObjectRead::Found {
+            object_type: format!(
+                "0x2::dynamic_field::Field<u64, {SEAL_PACKAGE}::key_server::KeyServerV{version}>"
+            ),
+            contents,
+        },
+    ))
+}
+
+#[tokio::test]
+async fn test_resolve_key_servers() -> anyhow::Result<()> {
+    let v2_id = ObjectID([1; 32]);
+    let v1_id = ObjectID([2; 32]);
+
+    let store = ObjectStore::new([
+        (v2_id, key_server_object()),
+        (v1_id, key_server_object()),
+        field_object(v2_id, KEY_SERVER_V2, committee_key_server())?,
+        field_object(
+            v1_id,
+            KEY_SERVER_V1,
+            KeyServerV1 {
+                name: "legacy".to_string(),
+                url: "https://legacy.example.com".to_string(),
+                key_type: 0,
+                pk: vec![3; 96],
+            },
+        )?,
+    ]);
+
+    let infos = resolve_key_servers(&[v1_id, v2_id], |object_ids| store.fetch(object_ids)).await?;
+
+    assert_eq!(infos[0].object_id, v1_id);
+    assert_eq!(infos[0].name, "legacy");
+    assert_eq!(infos[0].package_id, SEAL_PACKAGE.parse()?);
+    assert_eq!(infos[1].object_id, v2_id);
+    assert_eq!(infos[1].name, "committee");
+
+    // Key server objects and V2 fields first, then the V1 field of the legacy server.
+    assert_eq!(*store.calls.lock().unwrap(), vec![4, 1]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_resolve_key_servers_reports_invalid_objects() -> anyhow::Result<()> {
+    let key_server_id: ObjectID = KEY_SERVER_ID.parse()?;
+    let resolve = |object: Option<ObjectRead>| async move {
+        let store = ObjectStore::new(
+            object
+                .map(|object| (key_server_id, object))
+                .into_iter()
+                .chain([
+                    field_object(key_server_id, KEY_SERVER_V2, committee_key_server()).unwrap(),
+                ]),
+        );
+
+        resolve_key_servers(&[key_server_id], |object_ids| store.fetch(object_ids)).await
+    };
+
+    assert!(matches!(
+        resolve(None).await,
+        Err(KeyServerLayoutError::KeyServerNotFound { object_id }) if object_id == key_server_id
+    ));
+    assert!(matches!(
+        resolve(Some(ObjectRead::Deleted)).await,
+        Err(KeyServerLayoutError::KeyServerDeleted { object_id }) if object_id == key_server_id
+    ));
+    assert!(matches!(
+        resolve(Some(ObjectRead::Found {
+            object_type: "0x2::coin::Coin<0x2::sui::SUI>".to_string(),
+            contents: vec![],
+        }))
+        .await,
+        Err(KeyServerLayoutError::WrongKeyServerType { ref object_type, .. })
+            if object_type == "0x2::coin::Coin<0x2::sui::SUI>"
+    ));
+
+    let store = ObjectStore::new([(key_server_id, key_server_object())]);
+    assert!(matches!(
+        resolve_key_servers(&[key_server_id], |object_ids| store.fetch(object_ids)).await,
+        Err(KeyServerLayoutError::NoKeyServerVersion { object_id }) if object_id == key_server_id
+    ));
+
+    Ok(())
+}
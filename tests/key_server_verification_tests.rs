@@ -0,0 +1,312 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::utils::static_sui_client::{StaticSuiClient, key_server_url, master_key, public_key};
+use async_trait::async_trait;
+use fastcrypto::groups::HashToGroupElement;
+use fastcrypto::groups::bls12381::{G1Element, Scalar};
+use fastcrypto::serde_helpers::ToFromByteArray;
+use seal_sdk_rs::base_client::{BaseSealClient, DerivedKeys, KeyServerConfig, KeyServerInfo};
+use seal_sdk_rs::cache::NoCache;
+use seal_sdk_rs::cache_key::{DerivedKeyCacheKey, KeyServerInfoCacheKey};
+use seal_sdk_rs::crypto::{DST_POP, verify_proof_of_possession};
+use seal_sdk_rs::error::{ReqwestError, SealClientError};
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::http_client::{HttpClient, PostResponse};
+use seal_sdk_rs::json_rpc::sui_client::JsonRpcSuiClientError;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub mod utils;
+
+const KEY_SERVER_ID: ObjectID = ObjectID([0x42; 32]);
+
+/// Proof of possession as produced by the key server holding the master key `key`.
+fn proof_of_possession(key: u128, message: &[u8]) -> G1Element {
+    let mut full_message = DST_POP.to_vec();
+    full_message.extend(public_key(key).to_byte_array());
+    full_message.extend(message);
+
+    G1Element::hash_to_group_element(&full_message) * Scalar::from(key)
+}
+
+fn service_url() -> String {
+    format!(
+        "{}/v1/service?service_id={KEY_SERVER_ID}",
+        key_server_url(KEY_SERVER_ID)
+    )
+}
+
+fn service_response(service_id: ObjectID, pop: G1Element) -> String {
+    json!({ "service_id": service_id, "pop": pop }).to_string()
+}
+
+/// Serves canned GET responses by URL and records the requested URLs.
+#[derive(Clone, Default)]
+struct ServiceHttpClient {
+    responses: Arc<HashMap<String, String>>,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl ServiceHttpClient {
+    fn new(responses: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            responses: Arc::new(responses.into_iter().collect()),
+            requests: Arc::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl HttpClient for ServiceHttpClient {
+    type PostError = ReqwestError;
+
+    async fn post<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+        _body: S,
+    ) -> Result<PostResponse, Self::PostError> {
+        panic!("Unexpected POST to {url}");
+    }
+
+    async fn get(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+    ) -> Result<PostResponse, Self::PostError> {
+        self.requests.lock().unwrap().push(url.to_string());
+
+        Ok(match self.responses.get(url) {
+            Some(text) => PostResponse {
+                status: 200,
+                text: text.clone(),
+            },
+            None => PostResponse {
+                status: 404,
+                text: "Not Found".to_string(),
+            },
+        })
+    }
+}
+
+fn seal_client(
+    http_client: ServiceHttpClient,
+) -> BaseSealClient<
+    Arc<tokio::sync::Mutex<HashMap<KeyServerInfoCacheKey, KeyServerInfo>>>,
+    NoCache<DerivedKeyCacheKey, DerivedKeys>,
+    JsonRpcSuiClientError,
+    StaticSuiClient,
+    ReqwestError,
+    ServiceHttpClient,
+> {
+    BaseSealClient::new_custom(
+        Arc::default(),
+        NoCache::default(),
+        StaticSuiClient,
+        http_client,
+    )
+    .with_key_server_verification(true)
+}
+
+fn key_server_configs() -> Vec<KeyServerConfig> {
+    vec![KeyServerConfig::new(KEY_SERVER_ID, None)]
+}
+
+#[test]
+fn test_verify_proof_of_possession() {
+    let pop = proof_of_possession(7, &KEY_SERVER_ID.0);
+
+    assert!(verify_proof_of_possession(&pop, &public_key(7), &KEY_SERVER_ID.0).is_ok());
+    assert!(verify_proof_of_possession(&pop, &public_key(8), &KEY_SERVER_ID.0).is_err());
+    assert!(verify_proof_of_possession(&pop, &public_key(7), &[0x43; 32]).is_err());
+}
+
+#[tokio::test]
+async fn test_key_servers_are_verified_on_first_use() -> anyhow::Result<()> {
+    let http_client = ServiceHttpClient::new([(
+        service_url(),
+        service_response(
+            KEY_SERVER_ID,
+            proof_of_possession(master_key(KEY_SERVER_ID), &KEY_SERVER_ID.0),
+        ),
+    )]);
+    let client = seal_client(http_client.clone());
+
+    assert!(client.key_server_verification());
+
+    client.key_server_info(key_server_configs()).await?;
+    client.get_key_server_info(KEY_SERVER_ID).await?;
+
+    // Verified once, then served from the cache.
+    assert_eq!(*http_client.requests.lock().unwrap(), vec![service_url()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_key_server_verification_is_opt_in() -> anyhow::Result<()> {
+    let http_client = ServiceHttpClient::default();
+    let client = seal_client(http_client.clone()).with_key_server_verification(false);
+
+    client.key_server_info(key_server_configs()).await?;
+
+    assert!(http_client.requests.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_key_server_with_another_key_is_rejected() -> anyhow::Result<()> {
+    // The URL now serves a different master key than the one registered on-chain.
+    let http_client = ServiceHttpClient::new([(
+        service_url(),
+        service_response(
+            KEY_SERVER_ID,
+            proof_of_possession(master_key(KEY_SERVER_ID) + 1, &KEY_SERVER_ID.0),
+        ),
+    )]);
+    let client = seal_client(http_client.clone());
+
+    let result = client.key_server_info(key_server_configs()).await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::KeyServerVerificationFailed { object_id, .. }) if object_id == KEY_SERVER_ID
+    ));
+
+    // Failed verifications are not cached.
+    let result = client.get_key_server_info(KEY_SERVER_ID).await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::KeyServerVerificationFailed { .. })
+    ));
+    assert_eq!(http_client.requests.lock().unwrap().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_key_server_for_another_service_is_rejected() -> anyhow::Result<()> {
+    let other_service = ObjectID([0x43; 32]);
+    let http_client = ServiceHttpClient::new([(
+        service_url(),
+        service_response(
+            other_service,
+            proof_of_possession(master_key(KEY_SERVER_ID), &other_service.0),
+        ),
+    )]);
+
+    let result = seal_client(http_client)
+        .key_server_info(key_server_configs())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::KeyServerVerificationFailed { .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_key_server_service_error_is_reported() -> anyhow::Result<()> {
+    let result = seal_client(ServiceHttpClient::default())
+        .key_server_info(key_server_configs())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::ErrorWhileVerifyingKeyServer { ref url, status: 404, .. })
+            if *url == service_url()
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_untrusted_key_server_is_not_contacted() -> anyhow::Result<()> {
+    let http_client = ServiceHttpClient::new([(
+        service_url(),
+        service_response(
+            KEY_SERVER_ID,
+            proof_of_possession(master_key(KEY_SERVER_ID), &KEY_SERVER_ID.0),
+        ),
+    )]);
+    let client =
+        seal_client(http_client.clone()).with_trusted_seal_packages([ObjectID([0x5f; 32])]);
+
+    let result = client.key_server_info(key_server_configs()).await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::UntrustedKeyServerPackage { object_id, .. }) if object_id == KEY_SERVER_ID
+    ));
+
+    let result = client.get_key_server_info(KEY_SERVER_ID).await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::UntrustedKeyServerPackage { .. })
+    ));
+    assert!(http_client.requests.lock().unwrap().is_empty());
+
+    Ok(())
+}
+
+/// Client relying on the default `get`.
+struct PostOnlyHttpClient;
+
+#[async_trait]
+impl HttpClient for PostOnlyHttpClient {
+    type PostError = ReqwestError;
+
+    async fn post<S: ToString + Send + Sync>(
+        &self,
+        url: &str,
+        _headers: HashMap<String, String>,
+        _body: S,
+    ) -> Result<PostResponse, Self::PostError> {
+        panic!("Unexpected POST to {url}");
+    }
+}
+
+#[tokio::test]
+async fn test_verification_without_get_is_reported() -> anyhow::Result<()> {
+    let client = BaseSealClient::<
+        NoCache<KeyServerInfoCacheKey, KeyServerInfo>,
+        NoCache<DerivedKeyCacheKey, DerivedKeys>,
+        JsonRpcSuiClientError,
+        StaticSuiClient,
+        ReqwestError,
+        PostOnlyHttpClient,
+    >::new_custom(
+        NoCache::default(),
+        NoCache::default(),
+        StaticSuiClient,
+        PostOnlyHttpClient,
+    )
+    .with_key_server_verification(true);
+
+    let result = client.key_server_info(key_server_configs()).await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::HttpGetNotImplemented { ref url }) if *url == service_url()
+    ));
+
+    Ok(())
+}
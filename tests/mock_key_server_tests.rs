@@ -0,0 +1,302 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::utils::PRIVATE_KEY_BECH32;
+use crate::utils::mock::{
+    ID, PACKAGE_ID, key_server_configs, mock_servers, mock_servers_with, round_trip, server_url,
+    sui_client,
+};
+use seal_sdk_rs::builder::SealClientBuilder;
+use seal_sdk_rs::error::{ApproveTransactionError, EncryptedObjectError, SealClientError};
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::http_client::HttpClient;
+use seal_sdk_rs::mock_key_server::{MockHttpClient, MockKeyPolicy, seal_approve_transaction};
+use seal_sdk_rs::session_key::SessionKey;
+use seal_sdk_rs::signer::keypair::KeypairSigner;
+use std::collections::HashMap;
+
+pub mod utils;
+
+const OTHER_PACKAGE_ID: ObjectID = ObjectID([0xbb; 32]);
+
+/// Three mock servers answering [`ID`] with `policies`.
+fn policy_mock_servers(policies: [MockKeyPolicy; 3]) -> MockHttpClient {
+    mock_servers_with(3, |index, server| server.with_policy(ID, policies[index]))
+}
+
+/// Round trip with a threshold of two, verifying the key servers first.
+async fn verified_round_trip(http_client: MockHttpClient) -> Result<Vec<u8>, SealClientError> {
+    let client = SealClientBuilder::new()
+        .sui_client(sui_client(&http_client))
+        .http_client(http_client.clone())
+        .verify_key_servers(true)
+        .build()?;
+
+    round_trip(&client, &http_client, 2).await
+}
+
+#[tokio::test]
+async fn test_mock_key_server_round_trip() -> anyhow::Result<()> {
+    let decrypted = verified_round_trip(mock_servers(3)).await?;
+
+    assert_eq!(decrypted, b"secret");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_key_server_tolerates_failures_below_threshold() -> anyhow::Result<()> {
+    let http_client = policy_mock_servers([
+        MockKeyPolicy::Allow,
+        MockKeyPolicy::Fail,
+        MockKeyPolicy::Allow,
+    ]);
+    let decrypted = verified_round_trip(http_client.clone()).await?;
+
+    assert_eq!(decrypted, b"secret");
+    assert!(
+        http_client
+            .servers()
+            .iter()
+            .all(|server| server.fetch_key_requests() == 1)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_key_server_denials_above_threshold() -> anyhow::Result<()> {
+    let result = verified_round_trip(policy_mock_servers([
+        MockKeyPolicy::Deny,
+        MockKeyPolicy::Allow,
+        MockKeyPolicy::Deny,
+    ]))
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::InsufficientKeys {
+            received: 1,
+            threshold: 2
+        })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mock_key_server_rejects_unknown_routes() -> anyhow::Result<()> {
+    let http_client = mock_servers(3);
+
+    let response = http_client
+        .post(&format!("{}/v1/unknown", server_url(0)), HashMap::new(), "")
+        .await?;
+    assert_eq!(response.status, 404);
+
+    let response = http_client
+        .post(
+            "https://unknown.example.com/v1/fetch_key",
+            HashMap::new(),
+            "",
+        )
+        .await?;
+    assert_eq!(response.status, 404);
+
+    let response = http_client
+        .post(
+            &format!("{}/v1/fetch_key", server_url(0)),
+            HashMap::new(),
+            "{}",
+        )
+        .await?;
+    assert_eq!(response.status, 400);
+
+    Ok(())
+}
+
+async fn decrypt_with_session_package(
+    http_client: &MockHttpClient,
+    session_package_id: ObjectID,
+    approve_package_id: ObjectID,
+    package_versions: Option<&[ObjectID]>,
+) -> Result<Vec<u8>, SealClientError> {
+    let mut builder = SealClientBuilder::new()
+        .sui_client(sui_client(http_client))
+        .http_client(http_client.clone());
+    if let Some(package_versions) = package_versions {
+        builder = builder.package_versions(PACKAGE_ID, package_versions.iter().copied());
+    }
+    let client = builder.build()?;
+
+    let (encrypted, _recovery_key) = client
+        .encrypt_bytes(
+            PACKAGE_ID,
+            ID.to_vec(),
+            2,
+            key_server_configs(http_client),
+            b"secret".to_vec(),
+        )
+        .await?;
+
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32).unwrap();
+    let session_key = SessionKey::new(session_package_id, None, 5, &mut signer).await?;
+    let approve_transaction =
+        seal_approve_transaction(approve_package_id, "policy", &[ID.to_vec()])?;
+
+    client
+        .decrypt_object_bytes(
+            &bcs::to_bytes(&encrypted)?,
+            &approve_transaction,
+            &session_key,
+            HashMap::new(),
+        )
+        .await
+}
+
+#[tokio::test]
+async fn test_decrypt_rejects_object_from_another_package() -> anyhow::Result<()> {
+    let http_client = mock_servers(3);
+
+    let result =
+        decrypt_with_session_package(&http_client, OTHER_PACKAGE_ID, OTHER_PACKAGE_ID, None).await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::PackageIdMismatch {
+            object_package_id: PACKAGE_ID,
+            session_package_id: OTHER_PACKAGE_ID
+        })
+    ));
+    assert!(
+        http_client
+            .servers()
+            .iter()
+            .all(|server| server.fetch_key_requests() == 0)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decrypt_rejects_unregistered_package_version() -> anyhow::Result<()> {
+    let http_client = mock_servers(3);
+
+    // Registering versions, even none, restricts the PTB to the known ones.
+    let result =
+        decrypt_with_session_package(&http_client, PACKAGE_ID, OTHER_PACKAGE_ID, Some(&[])).await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::ApproveTransaction(
+            ApproveTransactionError::PackageMismatch {
+                package_id: OTHER_PACKAGE_ID,
+                session_package_id: PACKAGE_ID
+            }
+        ))
+    ));
+    assert!(
+        http_client
+            .servers()
+            .iter()
+            .all(|server| server.fetch_key_requests() == 0)
+    );
+
+    Ok(())
+}
+
+/// Mock servers treating [`OTHER_PACKAGE_ID`] as an upgrade of [`PACKAGE_ID`].
+fn upgraded_mock_servers() -> MockHttpClient {
+    mock_servers_with(3, |_, server| {
+        server.with_package_upgrade(PACKAGE_ID, OTHER_PACKAGE_ID)
+    })
+}
+
+#[tokio::test]
+async fn test_decrypt_through_upgraded_package_without_registration() -> anyhow::Result<()> {
+    let http_client = upgraded_mock_servers();
+
+    let decrypted =
+        decrypt_with_session_package(&http_client, PACKAGE_ID, OTHER_PACKAGE_ID, None).await?;
+
+    assert_eq!(decrypted, b"secret");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_package_versions_accept_upgraded_approve_transaction() -> anyhow::Result<()> {
+    let http_client = upgraded_mock_servers();
+
+    let decrypted = decrypt_with_session_package(
+        &http_client,
+        PACKAGE_ID,
+        OTHER_PACKAGE_ID,
+        Some(&[OTHER_PACKAGE_ID]),
+    )
+    .await?;
+
+    assert_eq!(decrypted, b"secret");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decrypt_rejects_malformed_object_before_fetching_keys() -> anyhow::Result<()> {
+    let http_client = mock_servers(3);
+    let client = SealClientBuilder::new()
+        .sui_client(sui_client(&http_client))
+        .http_client(http_client.clone())
+        .build()?;
+
+    let (mut encrypted, _recovery_key) = client
+        .encrypt_bytes(
+            PACKAGE_ID,
+            ID.to_vec(),
+            2,
+            key_server_configs(&http_client),
+            b"secret".to_vec(),
+        )
+        .await?;
+    encrypted.threshold = 4;
+
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32).unwrap();
+    let session_key = SessionKey::new(PACKAGE_ID, None, 5, &mut signer).await?;
+    let approve_transaction = seal_approve_transaction(PACKAGE_ID, "policy", &[ID.to_vec()])?;
+
+    let result = client
+        .decrypt_object_bytes(
+            &bcs::to_bytes(&encrypted)?,
+            &approve_transaction,
+            &session_key,
+            HashMap::new(),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::EncryptedObject(
+            EncryptedObjectError::InvalidThreshold {
+                threshold: 4,
+                services: 3
+            }
+        ))
+    ));
+    assert!(
+        http_client
+            .servers()
+            .iter()
+            .all(|server| server.fetch_key_requests() == 0)
+    );
+
+    Ok(())
+}
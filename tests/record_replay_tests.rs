@@ -0,0 +1,215 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::utils::PRIVATE_KEY_BECH32;
+use crate::utils::mock::{ID, PACKAGE_ID, key_server_configs, mock_servers, sui_client};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use seal_sdk_rs::builder::SealClientBuilder;
+use seal_sdk_rs::crypto::EncryptedObject;
+use seal_sdk_rs::error::SealClientError;
+use seal_sdk_rs::http_client::HttpClient;
+use seal_sdk_rs::in_memory_sui_client::InMemorySuiClient;
+use seal_sdk_rs::mock_key_server::{MockHttpClient, seal_approve_transaction};
+use seal_sdk_rs::record_replay::{HttpTrace, RecordingHttpClient, ReplayError, ReplayHttpClient};
+use seal_sdk_rs::session_key::SessionKey;
+use seal_sdk_rs::signer::keypair::KeypairSigner;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub mod utils;
+
+async fn decrypt<H, E>(
+    http_client: H,
+    sui_client: InMemorySuiClient,
+    seed: u64,
+    encrypted: &EncryptedObject,
+    session_key: &SessionKey,
+) -> Result<Vec<u8>, SealClientError>
+where
+    H: HttpClient<PostError = E>,
+    SealClientError: From<E>,
+{
+    let client = SealClientBuilder::new()
+        .sui_client(sui_client)
+        .http_client(http_client)
+        .rng(StdRng::seed_from_u64(seed))
+        .build()?;
+
+    let approve_transaction = seal_approve_transaction(PACKAGE_ID, "policy", &[ID.to_vec()])?;
+
+    client
+        .decrypt_object_bytes(
+            &bcs::to_bytes(encrypted)?,
+            &approve_transaction,
+            session_key,
+            HashMap::new(),
+        )
+        .await
+}
+
+/// Encrypt to the mock servers and record one decryption.
+async fn record() -> anyhow::Result<(HttpTrace, MockHttpClient, EncryptedObject, SessionKey)> {
+    let mock_servers = mock_servers(2);
+    let key_servers = key_server_configs(&mock_servers);
+
+    let (encrypted, _recovery_key) = SealClientBuilder::new()
+        .sui_client(sui_client(&mock_servers))
+        .build()?
+        .encrypt_bytes(PACKAGE_ID, ID.to_vec(), 2, key_servers, b"secret".to_vec())
+        .await?;
+
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+    let session_key = SessionKey::new(PACKAGE_ID, None, 5, &mut signer).await?;
+
+    let recorder = Arc::new(RecordingHttpClient::new(mock_servers.clone()));
+    let decrypted = decrypt(
+        recorder.clone(),
+        sui_client(&mock_servers),
+        1,
+        &encrypted,
+        &session_key,
+    )
+    .await?;
+    assert_eq!(decrypted, b"secret");
+
+    Ok((recorder.trace(), mock_servers, encrypted, session_key))
+}
+
+#[tokio::test]
+async fn test_replay_recorded_decryption() -> anyhow::Result<()> {
+    let (trace, mock_servers, encrypted, session_key) = record().await?;
+
+    assert_eq!(trace.exchanges.len(), 2);
+    assert!(
+        trace
+            .exchanges
+            .iter()
+            .all(|exchange| exchange.method == "POST" && exchange.url.ends_with("/v1/fetch_key"))
+    );
+
+    let path = std::env::temp_dir().join(format!("seal-trace-{}.json", std::process::id()));
+    trace.save(&path)?;
+    let replay = Arc::new(ReplayHttpClient::from_file(&path)?.with_body_matching(true));
+    std::fs::remove_file(&path)?;
+
+    let decrypted = decrypt(
+        replay.clone(),
+        sui_client(&mock_servers),
+        1,
+        &encrypted,
+        &session_key,
+    )
+    .await?;
+
+    assert_eq!(decrypted, b"secret");
+    assert_eq!(replay.remaining(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replay_detects_different_requests() -> anyhow::Result<()> {
+    let (trace, mock_servers, encrypted, session_key) = record().await?;
+
+    let replay = ReplayHttpClient::new(trace.clone()).with_body_matching(true);
+    let result = decrypt(
+        replay,
+        sui_client(&mock_servers),
+        2,
+        &encrypted,
+        &session_key,
+    )
+    .await;
+    assert!(matches!(
+        result,
+        Err(SealClientError::InsufficientKeys { received: 0, .. })
+    ));
+
+    // Without body matching, the responses are served but encrypted to other keys.
+    let replay = ReplayHttpClient::new(trace);
+    let result = decrypt(
+        replay,
+        sui_client(&mock_servers),
+        2,
+        &encrypted,
+        &session_key,
+    )
+    .await;
+    assert!(matches!(result, Err(SealClientError::FastCrypto(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_replay_without_recorded_exchange() -> anyhow::Result<()> {
+    let replay = ReplayHttpClient::new(HttpTrace::default());
+
+    let result = replay
+        .get(
+            "https://key-server-0.example.com/v1/service",
+            HashMap::new(),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ReplayError::NoRecordedExchange { method, .. }) if method == "GET"
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_seeded_encryption_is_reproducible() -> anyhow::Result<()> {
+    let mock_servers = mock_servers(2);
+    let encrypt = |seed: u64| {
+        let mock_servers = mock_servers.clone();
+        async move {
+            SealClientBuilder::new()
+                .sui_client(sui_client(&mock_servers))
+                .rng(StdRng::seed_from_u64(seed))
+                .build()?
+                .encrypt_bytes(
+                    PACKAGE_ID,
+                    ID.to_vec(),
+                    2,
+                    key_server_configs(&mock_servers),
+                    b"secret".to_vec(),
+                )
+                .await
+        }
+    };
+
+    let (first, first_key) = encrypt(7).await?;
+    let (second, second_key) = encrypt(7).await?;
+    let (other, _) = encrypt(8).await?;
+
+    assert_eq!(bcs::to_bytes(&first)?, bcs::to_bytes(&second)?);
+    assert_eq!(first_key, second_key);
+    assert_ne!(bcs::to_bytes(&first)?, bcs::to_bytes(&other)?);
+
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+    let session_key = SessionKey::new(PACKAGE_ID, None, 5, &mut signer).await?;
+    let decrypted = decrypt(
+        mock_servers.clone(),
+        sui_client(&mock_servers),
+        1,
+        &first,
+        &session_key,
+    )
+    .await?;
+    assert_eq!(decrypted, b"secret");
+
+    Ok(())
+}
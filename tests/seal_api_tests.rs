@@ -0,0 +1,191 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::utils::PRIVATE_KEY_BECH32;
+use crate::utils::mock::PACKAGE_ID;
+use crate::utils::static_sui_client::{StaticSuiClient, key_server_info};
+use async_trait::async_trait;
+use seal_sdk_rs::base_client::{KeyServerConfig, KeyServerInfo};
+use seal_sdk_rs::builder::SealClientBuilder;
+use seal_sdk_rs::crypto::EncryptedObject;
+use seal_sdk_rs::error::SealClientError;
+use seal_sdk_rs::generic_types::{BCSSerializableProgrammableTransaction, ObjectID};
+use seal_sdk_rs::seal_api::{ApproveTransaction, SealApi, SealApiExt};
+use seal_sdk_rs::session_key::SessionKey;
+use seal_sdk_rs::signer::keypair::KeypairSigner;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub mod utils;
+
+struct DemoTransaction;
+
+impl BCSSerializableProgrammableTransaction for DemoTransaction {
+    fn to_bcs_bytes(&self) -> Result<Vec<u8>, SealClientError> {
+        Ok(vec![0xde, 0xad])
+    }
+}
+
+/// Test double standing in for a real client in business logic tests.
+#[derive(Default)]
+struct FakeSeal {
+    plaintexts: Vec<Vec<u8>>,
+    decrypt_calls: Mutex<Vec<(usize, Vec<u8>)>>,
+}
+
+#[async_trait]
+impl SealApi for FakeSeal {
+    async fn get_key_server_info(
+        &self,
+        key_server_id: ObjectID,
+    ) -> Result<KeyServerInfo, SealClientError> {
+        Ok(key_server_info(key_server_id))
+    }
+
+    async fn key_server_info(
+        &self,
+        key_servers: Vec<KeyServerConfig>,
+    ) -> Result<Vec<KeyServerInfo>, SealClientError> {
+        Ok(key_servers
+            .iter()
+            .map(|key_server| key_server_info(key_server.object_id()))
+            .collect())
+    }
+
+    async fn encrypt_multiple_bytes(
+        &self,
+        _package_id: ObjectID,
+        _id: Vec<u8>,
+        threshold: u8,
+        _key_servers: Vec<KeyServerConfig>,
+        _data: Vec<Vec<u8>>,
+    ) -> Result<Vec<(EncryptedObject, [u8; 32])>, SealClientError> {
+        Err(SealClientError::InsufficientKeys {
+            received: 0,
+            threshold,
+        })
+    }
+
+    async fn decrypt_multiple_objects_bytes(
+        &self,
+        encrypted_objects_data: &[&[u8]],
+        approve_transaction_data: ApproveTransaction<'_>,
+        _session_key: &SessionKey,
+        _aggregator_urls_for_ker_server: HashMap<ObjectID, String>,
+    ) -> Result<Vec<Vec<u8>>, SealClientError> {
+        self.decrypt_calls.lock().unwrap().push((
+            encrypted_objects_data.len(),
+            approve_transaction_data.to_bcs_bytes()?,
+        ));
+
+        Ok(self.plaintexts.clone())
+    }
+}
+
+/// Business logic written against the trait object only.
+struct Vault {
+    seal: Arc<dyn SealApi>,
+}
+
+impl Vault {
+    async fn server_names(&self, key_servers: &[u8]) -> Result<Vec<String>, SealClientError> {
+        let configs = key_servers
+            .iter()
+            .map(|&id| KeyServerConfig::new(ObjectID([id; 32]), None))
+            .collect();
+
+        Ok(self
+            .seal
+            .key_server_info(configs)
+            .await?
+            .into_iter()
+            .map(|info| info.name)
+            .collect())
+    }
+}
+
+#[tokio::test]
+async fn test_seal_api_trait_object_accepts_test_doubles() -> anyhow::Result<()> {
+    let fake = Arc::new(FakeSeal {
+        plaintexts: vec![bcs::to_bytes("secret")?],
+        ..Default::default()
+    });
+    let vault = Vault { seal: fake.clone() };
+
+    assert_eq!(
+        vault.server_names(&[1, 2]).await?,
+        vec!["server-1", "server-2"]
+    );
+
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+    let session_key = SessionKey::new(PACKAGE_ID, None, 5, &mut signer).await?;
+
+    // Typed helpers go through the single-object default, then the fake's batch method.
+    let decrypted: String = vault
+        .seal
+        .decrypt_object(&[1, 2, 3], &DemoTransaction, &session_key, HashMap::new())
+        .await?;
+
+    assert_eq!(decrypted, "secret");
+    assert_eq!(
+        *fake.decrypt_calls.lock().unwrap(),
+        vec![(1, vec![0xde, 0xad])]
+    );
+
+    let result = vault
+        .seal
+        .encrypt(PACKAGE_ID, vec![1], 1, vec![], "payload")
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SealClientError::InsufficientKeys { threshold: 1, .. })
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_base_seal_client_is_a_seal_api() -> anyhow::Result<()> {
+    let seal: Arc<dyn SealApi> = Arc::new(
+        SealClientBuilder::new()
+            .sui_client(StaticSuiClient)
+            .build()?,
+    );
+
+    let info = seal.get_key_server_info(ObjectID([3; 32])).await?;
+    assert_eq!(info.name, "server-3");
+
+    let key_servers = vec![
+        KeyServerConfig::new(ObjectID([1; 32]), None),
+        KeyServerConfig::new(ObjectID([2; 32]), None),
+    ];
+    let (encrypted, _recovery_key) = seal
+        .encrypt(PACKAGE_ID, vec![7, 7], 2, key_servers, 42u64)
+        .await?;
+
+    assert_eq!(encrypted.package_id, PACKAGE_ID);
+    assert_eq!(encrypted.id, vec![7, 7]);
+    assert_eq!(encrypted.threshold, 2);
+    assert_eq!(
+        encrypted
+            .services
+            .iter()
+            .map(|(object_id, _)| *object_id)
+            .collect::<Vec<_>>(),
+        vec![ObjectID([1; 32]), ObjectID([2; 32])]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use fastcrypto::groups::bls12381::G2Element;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use seal_crypto::{
+    EncryptionInput, IBEPublicKeys, IBEUserSecretKeys, create_full_id, ibe, seal_decrypt,
+    seal_encrypt,
+};
+use seal_sdk_rs::crypto::{EncryptedObject, seal_encrypt_with_rng};
+use seal_sdk_rs::generic_types::ObjectID;
+use std::collections::HashMap;
+
+const PACKAGE_ID: ObjectID = ObjectID([0x11; 32]);
+const ID: &[u8] = b"seal-encrypt-tests";
+const THRESHOLD: u8 = 2;
+
+struct KeyServers {
+    ids: Vec<ObjectID>,
+    master_keys: Vec<ibe::MasterKey>,
+    public_keys: Vec<G2Element>,
+}
+
+impl KeyServers {
+    fn new(count: u8) -> Self {
+        let mut rng = StdRng::seed_from_u64(42);
+        let (master_keys, public_keys) =
+            (0..count).map(|_| ibe::generate_key_pair(&mut rng)).unzip();
+
+        Self {
+            ids: (0..count).map(|index| ObjectID([index + 1; 32])).collect(),
+            master_keys,
+            public_keys,
+        }
+    }
+
+    fn ibe_public_keys(&self) -> IBEPublicKeys {
+        IBEPublicKeys::BonehFranklinBLS12381(self.public_keys.clone())
+    }
+
+    /// User secret keys for `full_id`, as the key servers would return them.
+    fn user_secret_keys(&self, full_id: &[u8]) -> IBEUserSecretKeys {
+        IBEUserSecretKeys::BonehFranklinBLS12381(
+            self.ids
+                .iter()
+                .zip(&self.master_keys)
+                .map(|(id, master_key)| ((*id).into(), ibe::extract(master_key, full_id)))
+                .collect::<HashMap<_, _>>(),
+        )
+    }
+
+    /// Decrypt with upstream `seal_decrypt`, checking the shares against the public keys.
+    fn decrypt(&self, encrypted_object: EncryptedObject) -> anyhow::Result<Vec<u8>> {
+        let full_id = create_full_id(&PACKAGE_ID.0, ID);
+
+        Ok(seal_decrypt(
+            &encrypted_object.into(),
+            &self.user_secret_keys(&full_id),
+            Some(&self.ibe_public_keys()),
+        )?)
+    }
+}
+
+fn input() -> EncryptionInput {
+    EncryptionInput::Aes256Gcm {
+        data: b"secret".to_vec(),
+        aad: Some(b"aad".to_vec()),
+    }
+}
+
+fn encrypt_with_seed(
+    key_servers: &KeyServers,
+    seed: u64,
+) -> anyhow::Result<(EncryptedObject, [u8; 32])> {
+    Ok(seal_encrypt_with_rng(
+        PACKAGE_ID,
+        ID.to_vec(),
+        key_servers.ids.clone(),
+        &key_servers.ibe_public_keys(),
+        THRESHOLD,
+        input(),
+        &mut StdRng::seed_from_u64(seed),
+    )?)
+}
+
+#[test]
+fn test_seal_encrypt_with_rng_output_decrypts_with_upstream() -> anyhow::Result<()> {
+    let key_servers = KeyServers::new(3);
+
+    for input in [
+        input(),
+        EncryptionInput::Hmac256Ctr {
+            data: b"secret".to_vec(),
+            aad: None,
+        },
+    ] {
+        let (encrypted_object, _) = seal_encrypt_with_rng(
+            PACKAGE_ID,
+            ID.to_vec(),
+            key_servers.ids.clone(),
+            &key_servers.ibe_public_keys(),
+            THRESHOLD,
+            input,
+            &mut StdRng::seed_from_u64(7),
+        )?;
+
+        assert_eq!(key_servers.decrypt(encrypted_object)?, b"secret");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_seal_encrypt_with_rng_matches_upstream_seal_encrypt() -> anyhow::Result<()> {
+    let key_servers = KeyServers::new(3);
+
+    // A fixed RNG gives the same object and key on every call.
+    let (encrypted_object, key) = encrypt_with_seed(&key_servers, 7)?;
+    let (replayed_object, replayed_key) = encrypt_with_seed(&key_servers, 7)?;
+    assert_eq!(encrypted_object.to_bytes()?, replayed_object.to_bytes()?);
+    assert_eq!(key, replayed_key);
+
+    let (other_object, _) = encrypt_with_seed(&key_servers, 8)?;
+    assert_ne!(encrypted_object.to_bytes()?, other_object.to_bytes()?);
+
+    // Upstream `seal_encrypt` draws from `thread_rng()`, so its randomness cannot be fixed. Every
+    // field that does not depend on it must match, and the encodings must have the same length.
+    let (upstream_object, _) = seal_encrypt(
+        PACKAGE_ID.into(),
+        ID.to_vec(),
+        key_servers.ids.iter().map(|id| (*id).into()).collect(),
+        &key_servers.ibe_public_keys(),
+        THRESHOLD,
+        input(),
+    )?;
+    let upstream_object = EncryptedObject::from(upstream_object);
+
+    assert_eq!(encrypted_object.describe(), upstream_object.describe());
+    assert_eq!(
+        encrypted_object.to_bytes()?.len(),
+        upstream_object.to_bytes()?.len()
+    );
+
+    assert_eq!(key_servers.decrypt(encrypted_object)?, b"secret");
+    assert_eq!(key_servers.decrypt(upstream_object)?, b"secret");
+
+    Ok(())
+}
@@ -0,0 +1,229 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::utils::PRIVATE_KEY_BECH32;
+use fastcrypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
+use fastcrypto::hash::{Blake2b256, HashFunction};
+use fastcrypto::traits::{ToFromBytes, VerifyingKey};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use seal_sdk_rs::clock::FixedClock;
+use seal_sdk_rs::crypto::{Certificate, FetchKeyRequest};
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::session_key::{SessionKey, signed_message};
+use seal_sdk_rs::signer::Signer;
+use seal_sdk_rs::signer::keypair::KeypairSigner;
+use std::sync::Arc;
+use std::time::Duration;
+use sui_sdk_types::{SimpleSignature, UserSignature};
+
+pub mod utils;
+
+/// Intent prefix (`PersonalMessage` scope, version 0, Sui app) of personal message signatures.
+const PERSONAL_MESSAGE_INTENT: [u8; 3] = [3, 0, 0];
+const NOW_MS: u64 = 1_700_000_000_123;
+
+#[tokio::test]
+async fn test_session_key_uses_a_single_timestamp() -> anyhow::Result<()> {
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+
+    let session_key = SessionKey::builder(ObjectID([1; 32]), 5)
+        .clock(FixedClock::new(NOW_MS))
+        .build(&mut signer)
+        .await?;
+
+    let (request, _) = session_key.get_fetch_key_request(vec![])?;
+
+    assert_eq!(session_key.creation_time_ms(), NOW_MS);
+    assert_eq!(request.certificate.creation_time, NOW_MS);
+    assert_eq!(request.certificate.ttl_min, 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_session_key_clock_skew_compensation() -> anyhow::Result<()> {
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+
+    let session_key = SessionKey::builder(ObjectID([1; 32]), 5)
+        .clock(FixedClock::new(NOW_MS))
+        .clock_skew_compensation(Duration::from_secs(5))
+        .build(&mut signer)
+        .await?;
+
+    assert_eq!(session_key.creation_time_ms(), NOW_MS - 5_000);
+    assert_eq!(
+        session_key.expiration_time_ms(),
+        NOW_MS - 5_000 + 5 * 60_000
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_session_key_expiration() -> anyhow::Result<()> {
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+    let clock = Arc::new(FixedClock::new(NOW_MS));
+
+    let session_key = SessionKey::builder(ObjectID([1; 32]), 1)
+        .clock(clock.clone())
+        .build(&mut signer)
+        .await?;
+
+    assert!(!session_key.is_expired(&clock));
+
+    clock.advance(Duration::from_secs(59));
+    assert!(!session_key.is_expired(&clock));
+
+    clock.advance(Duration::from_secs(1));
+    assert!(session_key.is_expired(&clock));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_session_key_without_mvr_name_omits_it() -> anyhow::Result<()> {
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+
+    let session_key = SessionKey::new(ObjectID([1; 32]), None, 5, &mut signer).await?;
+    let (request, _) = session_key.get_fetch_key_request(vec![])?;
+
+    let json: serde_json::Value = serde_json::from_str(&request.to_json_string()?)?;
+    assert!(json["certificate"].get("mvr_name").is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fetch_key_request_bcs_round_trip() -> anyhow::Result<()> {
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+
+    for mvr_name in [None, Some("@org/app".to_string())] {
+        let session_key =
+            SessionKey::new(ObjectID([1; 32]), mvr_name.clone(), 5, &mut signer).await?;
+        let (request, _) = session_key.get_fetch_key_request(vec![1, 2, 3])?;
+
+        let bytes = bcs::to_bytes(&request)?;
+        let decoded: FetchKeyRequest = bcs::from_bytes(&bytes)?;
+        assert_eq!(decoded.certificate.mvr_name, mvr_name);
+        assert_eq!(bcs::to_bytes(&decoded)?, bytes);
+
+        let bytes = bcs::to_bytes(&request.certificate)?;
+        let decoded: Certificate = bcs::from_bytes(&bytes)?;
+        assert_eq!(decoded.mvr_name, mvr_name);
+        assert_eq!(bcs::to_bytes(&decoded)?, bytes);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_session_key_mvr_name() -> anyhow::Result<()> {
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+
+    let session_key = SessionKey::new(
+        ObjectID([1; 32]),
+        Some("@org/app".to_string()),
+        5,
+        &mut signer,
+    )
+    .await?;
+
+    let (request, _) = session_key.get_fetch_key_request(vec![])?;
+
+    assert_eq!(session_key.mvr_name(), Some("@org/app"));
+    assert_eq!(request.certificate.mvr_name.as_deref(), Some("@org/app"));
+
+    let json: serde_json::Value = serde_json::from_str(&request.to_json_string()?)?;
+    assert_eq!(json["certificate"]["mvr_name"], "@org/app");
+
+    let certificate = &request.certificate;
+    assert_eq!(certificate.creation_time, session_key.creation_time_ms());
+    assert_eq!(certificate.ttl_min, session_key.ttl_min());
+
+    let message = signed_message(
+        "@org/app".to_string(),
+        &certificate.session_vk,
+        session_key.creation_time_ms(),
+        session_key.ttl_min(),
+    )
+    .unwrap();
+    assert!(message.starts_with("Accessing keys of package @org/app for 5 mins from "));
+
+    // The certificate signs the message with the personal-message intent.
+    let UserSignature::Simple(SimpleSignature::Ed25519 {
+        signature,
+        public_key,
+    }) = &certificate.signature
+    else {
+        panic!("expected an Ed25519 certificate signature");
+    };
+    assert_eq!(public_key.as_bytes(), signer.get_public_key()?.as_bytes());
+
+    let mut hasher = Blake2b256::default();
+    hasher.update(PERSONAL_MESSAGE_INTENT);
+    hasher.update(bcs::to_bytes(message.as_bytes())?);
+    let digest = hasher.finalize();
+
+    Ed25519PublicKey::from_bytes(public_key.as_bytes())?.verify(
+        &digest.digest,
+        &Ed25519Signature::from_bytes(signature.as_bytes())?,
+    )?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_session_key_rejects_invalid_ttl() -> anyhow::Result<()> {
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+
+    assert!(
+        SessionKey::new(ObjectID([1; 32]), None, 0, &mut signer)
+            .await
+            .is_err()
+    );
+    assert!(
+        SessionKey::new(ObjectID([1; 32]), None, 31, &mut signer)
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_session_key_with_seeded_rng_is_reproducible() -> anyhow::Result<()> {
+    async fn request_json(session_key_seed: u64, request_seed: u64) -> anyhow::Result<String> {
+        let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+
+        let session_key = SessionKey::builder(ObjectID([1; 32]), 5)
+            .clock(FixedClock::new(NOW_MS))
+            .rng(StdRng::seed_from_u64(session_key_seed))
+            .build(&mut signer)
+            .await?;
+
+        let (request, _) = session_key.get_fetch_key_request_with_rng(
+            vec![1, 2, 3],
+            &mut StdRng::seed_from_u64(request_seed),
+        )?;
+
+        Ok(request.to_json_string()?)
+    }
+
+    assert_eq!(request_json(1, 2).await?, request_json(1, 2).await?);
+    assert_ne!(request_json(1, 2).await?, request_json(3, 2).await?);
+    assert_ne!(request_json(1, 2).await?, request_json(1, 3).await?);
+
+    Ok(())
+}
@@ -0,0 +1,188 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::utils::PRIVATE_KEY_BECH32;
+use base64::Engine;
+use fastcrypto::ed25519::{Ed25519KeyPair, Ed25519PrivateKey, Ed25519PublicKey};
+use fastcrypto::hash::{Blake2b256, HashFunction};
+use fastcrypto::traits::ToFromBytes;
+use seal_sdk_rs::error::SessionKeyError;
+use seal_sdk_rs::generic_types::{ObjectID, SuiAddress};
+use seal_sdk_rs::session_key::SessionKey;
+use seal_sdk_rs::signer::keypair::KeypairSigner;
+use seal_sdk_rs::signer::keystore::{FileKeystoreSigner, KeystoreSignerError};
+use seal_sdk_rs::signer::{Signer, ed25519_sui_address};
+use std::str::FromStr;
+
+pub mod utils;
+
+const PRIVATE_KEY_BYTES: [u8; 32] = [7; 32];
+const PUBLIC_KEY_HEX: &str = "ea4a6c63e29c520abef5507b132ec5f9954776aebebe7b92421eea691446d22c";
+const ADDRESS: &str = "0xa0ccc8bcc83f6c628340134f8546a21e0618fd1aaa02432bba454c4a2c2233da";
+const HELLO_SEAL_SIGNATURE_HEX: &str = "b50de27bfa4dbcb4ef24a58dfd4c022f65171db6e23f890a3599fa66cc4e1cbe50ec23f2473811ec724d469a6743eca15b6ea5740c311005b05a7660ee98ff08";
+
+fn expected_address() -> SuiAddress {
+    ObjectID::from_str(ADDRESS).unwrap().0.into()
+}
+
+#[tokio::test]
+async fn test_keypair_signer_from_bech32() -> anyhow::Result<()> {
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?;
+
+    assert_eq!(
+        hex::encode(signer.get_public_key()?.as_bytes()),
+        PUBLIC_KEY_HEX
+    );
+    assert_eq!(signer.get_sui_address()?, expected_address());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_keypair_signer_signs_personal_message_intent() -> anyhow::Result<()> {
+    let private_key = Ed25519PrivateKey::from_bytes(&PRIVATE_KEY_BYTES)?;
+    let mut signer = KeypairSigner::new(Ed25519KeyPair::from(private_key));
+
+    let signature = signer.sign_personal_message(b"hello seal".to_vec()).await?;
+
+    assert_eq!(hex::encode(signature.as_bytes()), HELLO_SEAL_SIGNATURE_HEX);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_keypair_signer_rejects_invalid_keys() {
+    assert!(KeypairSigner::from_bech32("suiprivkey1invalid").is_err());
+    assert!(KeypairSigner::from_flagged_bytes(&[1; 33]).is_err());
+    assert!(KeypairSigner::from_flagged_bytes(&[0; 12]).is_err());
+}
+
+#[tokio::test]
+async fn test_file_keystore_signer() -> anyhow::Result<()> {
+    let mut flagged_key = vec![0u8];
+    flagged_key.extend_from_slice(&PRIVATE_KEY_BYTES);
+
+    let mut other_flagged_key = vec![0u8];
+    other_flagged_key.extend_from_slice(&[8; 32]);
+
+    let mut secp256k1_key = vec![1u8];
+    secp256k1_key.extend_from_slice(&[9; 32]);
+
+    let entries = [secp256k1_key, other_flagged_key, flagged_key]
+        .iter()
+        .map(|bytes| base64::engine::general_purpose::STANDARD.encode(bytes))
+        .collect::<Vec<_>>();
+
+    let path = std::env::temp_dir().join(format!("seal-sdk-rs-{}.keystore", std::process::id()));
+    std::fs::write(&path, serde_json::to_string(&entries)?)?;
+
+    let mut signer = FileKeystoreSigner::new(&path, expected_address())?;
+    assert_eq!(signer.get_sui_address()?, expected_address());
+
+    let session_key = SessionKey::new(ObjectID([1; 32]), None, 5, &mut signer).await?;
+    assert_eq!(session_key.address(), &expected_address());
+
+    let missing = FileKeystoreSigner::new(&path, SuiAddress([0; 32]));
+    assert!(matches!(
+        missing,
+        Err(KeystoreSignerError::AddressNotFound { .. })
+    ));
+
+    std::fs::remove_file(path)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_ed25519_sui_address_known_vector() -> anyhow::Result<()> {
+    let public_key_bytes = hex::decode(PUBLIC_KEY_HEX)?;
+    let public_key = Ed25519PublicKey::from_bytes(&public_key_bytes)?;
+
+    let mut flagged_public_key = vec![0x00];
+    flagged_public_key.extend_from_slice(&public_key_bytes);
+    let digest = Blake2b256::digest(&flagged_public_key).digest;
+
+    assert_eq!(ed25519_sui_address(&public_key), SuiAddress(digest));
+    assert_eq!(ed25519_sui_address(&public_key), expected_address());
+
+    Ok(())
+}
+
+/// Signer relying on the default `get_sui_address`.
+struct PublicKeyOnlySigner(KeypairSigner);
+
+#[async_trait::async_trait]
+impl Signer for PublicKeyOnlySigner {
+    type Error = SessionKeyError;
+
+    async fn sign_personal_message(
+        &mut self,
+        message: Vec<u8>,
+    ) -> Result<fastcrypto::ed25519::Ed25519Signature, Self::Error> {
+        Ok(self.0.sign_personal_message(message).await?)
+    }
+
+    fn get_public_key(&mut self) -> Result<Ed25519PublicKey, Self::Error> {
+        Ok(self.0.get_public_key()?)
+    }
+}
+
+#[tokio::test]
+async fn test_default_get_sui_address_derives_from_public_key() -> anyhow::Result<()> {
+    let mut signer = PublicKeyOnlySigner(KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?);
+
+    assert_eq!(signer.get_sui_address()?, expected_address());
+
+    let session_key = SessionKey::new(ObjectID([1; 32]), None, 5, &mut signer).await?;
+    assert_eq!(session_key.address(), &expected_address());
+
+    Ok(())
+}
+
+struct LyingSigner(KeypairSigner);
+
+#[async_trait::async_trait]
+impl Signer for LyingSigner {
+    type Error = SessionKeyError;
+
+    async fn sign_personal_message(
+        &mut self,
+        message: Vec<u8>,
+    ) -> Result<fastcrypto::ed25519::Ed25519Signature, Self::Error> {
+        Ok(self.0.sign_personal_message(message).await?)
+    }
+
+    fn get_public_key(&mut self) -> Result<fastcrypto::ed25519::Ed25519PublicKey, Self::Error> {
+        Ok(self.0.get_public_key()?)
+    }
+
+    fn get_sui_address(&mut self) -> Result<SuiAddress, Self::Error> {
+        Ok(SuiAddress([0; 32]))
+    }
+}
+
+#[tokio::test]
+async fn test_session_key_rejects_signer_address_mismatch() -> anyhow::Result<()> {
+    let mut signer = LyingSigner(KeypairSigner::from_bech32(PRIVATE_KEY_BECH32)?);
+
+    let result = SessionKey::new(ObjectID([1; 32]), None, 5, &mut signer).await;
+
+    assert!(matches!(
+        result,
+        Err(SessionKeyError::SignerAddressMismatch { claimed, derived })
+            if claimed == SuiAddress([0; 32]) && derived == expected_address()
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,305 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compatibility vectors shared with the TypeScript Seal SDK, see `tests/vectors/ts_sdk`.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use fastcrypto::ed25519::{Ed25519KeyPair, Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature};
+use fastcrypto::groups::bls12381::G2Element;
+use fastcrypto::serde_helpers::ToFromByteArray;
+use fastcrypto::traits::{ToFromBytes, VerifyingKey};
+use rand::rngs::StdRng;
+use rand::{CryptoRng, RngCore, SeedableRng};
+use seal_crypto::{Ciphertext, IBEEncryptions};
+use seal_sdk_rs::clock::FixedClock;
+use seal_sdk_rs::crypto::EncryptedObject;
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::session_key::{SessionKey, signed_message, signed_request};
+use seal_sdk_rs::signer::keypair::KeypairSigner;
+use serde::Deserialize;
+use serde_json::Value;
+use std::str::FromStr;
+
+const ENCRYPTED_OBJECTS: &str = include_str!("vectors/ts_sdk/encrypted_objects.json");
+const SIGNED_MESSAGES: &str = include_str!("vectors/ts_sdk/signed_messages.json");
+const FETCH_KEY_REQUESTS: &str = include_str!("vectors/ts_sdk/fetch_key_requests.json");
+
+/// User key of the vectors, the secret key of RFC 8032 test 1.
+const USER_SECRET_KEY: [u8; 32] = [
+    0x9d, 0x61, 0xb1, 0x9d, 0xef, 0xfd, 0x5a, 0x60, 0xba, 0x84, 0x4a, 0xf4, 0x92, 0xec, 0x2c, 0xc4,
+    0x44, 0x49, 0xc5, 0x69, 0x7b, 0x32, 0x69, 0x19, 0x70, 0x3b, 0xac, 0x03, 0x1c, 0xae, 0x7f, 0x60,
+];
+/// Session key of the vectors, the secret key of RFC 8032 test 2.
+const SESSION_SECRET_KEY: [u8; 32] = [
+    0x4c, 0xcd, 0x08, 0x9b, 0x28, 0xff, 0x96, 0xda, 0x9d, 0xb6, 0xc3, 0x46, 0xec, 0x11, 0x4e, 0x0f,
+    0x5b, 0x8a, 0x31, 0x9f, 0x35, 0xab, 0xa6, 0x24, 0xda, 0x8c, 0xf6, 0xed, 0x4f, 0xb8, 0xa6, 0xfb,
+];
+
+#[derive(Deserialize)]
+struct EncryptedObjectVector {
+    name: String,
+    bcs: String,
+    version: u8,
+    package_id: String,
+    id: String,
+    services: Vec<(String, u8)>,
+    threshold: u8,
+    nonce: String,
+    encrypted_shares: Vec<String>,
+    encrypted_randomness: String,
+    ciphertext: CiphertextVector,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum CiphertextVector {
+    Aes256Gcm {
+        blob: String,
+        aad: Option<String>,
+    },
+    Hmac256Ctr {
+        blob: String,
+        aad: Option<String>,
+        mac: String,
+    },
+    Plain,
+}
+
+#[derive(Deserialize)]
+struct SignedMessageVector {
+    package_name: String,
+    session_vk: String,
+    creation_time: u64,
+    ttl_min: u16,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct FetchKeyRequestVector {
+    name: String,
+    package_id: String,
+    json: Value,
+}
+
+fn hex_array<const N: usize>(value: &str) -> [u8; N] {
+    hex::decode(value).unwrap().try_into().unwrap()
+}
+
+impl EncryptedObjectVector {
+    fn to_encrypted_object(&self) -> EncryptedObject {
+        let decode_optional =
+            |aad: &Option<String>| aad.as_deref().map(|aad| hex::decode(aad).unwrap());
+
+        EncryptedObject {
+            version: self.version,
+            package_id: ObjectID::from_str(&self.package_id).unwrap(),
+            id: hex::decode(&self.id).unwrap(),
+            services: self
+                .services
+                .iter()
+                .map(|(object_id, index)| (ObjectID::from_str(object_id).unwrap(), *index))
+                .collect(),
+            threshold: self.threshold,
+            encrypted_shares: IBEEncryptions::BonehFranklinBLS12381 {
+                nonce: G2Element::from_byte_array(&hex_array(&self.nonce)).unwrap(),
+                encrypted_shares: self
+                    .encrypted_shares
+                    .iter()
+                    .map(|share| hex_array(share))
+                    .collect(),
+                encrypted_randomness: hex_array(&self.encrypted_randomness),
+            },
+            ciphertext: match &self.ciphertext {
+                CiphertextVector::Aes256Gcm { blob, aad } => Ciphertext::Aes256Gcm {
+                    blob: hex::decode(blob).unwrap(),
+                    aad: decode_optional(aad),
+                },
+                CiphertextVector::Hmac256Ctr { blob, aad, mac } => Ciphertext::Hmac256Ctr {
+                    blob: hex::decode(blob).unwrap(),
+                    aad: decode_optional(aad),
+                    mac: hex_array(mac),
+                },
+                CiphertextVector::Plain => Ciphertext::Plain,
+            },
+        }
+    }
+}
+
+#[test]
+fn test_encrypted_object_vectors_decode() -> anyhow::Result<()> {
+    let vectors: Vec<EncryptedObjectVector> = serde_json::from_str(ENCRYPTED_OBJECTS)?;
+
+    for vector in vectors {
+        let bytes = hex::decode(&vector.bcs)?;
+        let decoded: EncryptedObject = bcs::from_bytes(&bytes)?;
+        let expected = vector.to_encrypted_object();
+
+        assert_eq!(decoded.version, expected.version, "{}", vector.name);
+        assert_eq!(decoded.package_id, expected.package_id, "{}", vector.name);
+        assert_eq!(decoded.id, expected.id, "{}", vector.name);
+        assert_eq!(decoded.services, expected.services, "{}", vector.name);
+        assert_eq!(decoded.threshold, expected.threshold, "{}", vector.name);
+        assert_eq!(
+            bcs::to_bytes(&decoded.encrypted_shares)?,
+            bcs::to_bytes(&expected.encrypted_shares)?,
+            "{}",
+            vector.name
+        );
+        assert_eq!(
+            bcs::to_bytes(&decoded.ciphertext)?,
+            bcs::to_bytes(&expected.ciphertext)?,
+            "{}",
+            vector.name
+        );
+
+        // Re-encoding what we decoded gives back the exact bytes.
+        assert_eq!(bcs::to_bytes(&decoded)?, bytes, "{}", vector.name);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_encrypted_object_vectors_encode() -> anyhow::Result<()> {
+    let vectors: Vec<EncryptedObjectVector> = serde_json::from_str(ENCRYPTED_OBJECTS)?;
+
+    for vector in vectors {
+        let encrypted_object = vector.to_encrypted_object();
+
+        assert_eq!(
+            hex::encode(bcs::to_bytes(&encrypted_object)?),
+            vector.bcs,
+            "{}",
+            vector.name
+        );
+
+        // The upstream type uses the same layout.
+        let upstream: seal_crypto::EncryptedObject = encrypted_object.into();
+        assert_eq!(
+            hex::encode(bcs::to_bytes(&upstream)?),
+            vector.bcs,
+            "{}",
+            vector.name
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_signed_message_vectors() -> anyhow::Result<()> {
+    let vectors: Vec<SignedMessageVector> = serde_json::from_str(SIGNED_MESSAGES)?;
+
+    for vector in vectors {
+        let session_vk = Ed25519PublicKey::from_bytes(&BASE64.decode(&vector.session_vk)?)?;
+
+        let message = signed_message(
+            vector.package_name.clone(),
+            &session_vk,
+            vector.creation_time,
+            vector.ttl_min,
+        );
+
+        assert_eq!(message.as_deref(), Some(vector.message.as_str()));
+    }
+
+    Ok(())
+}
+
+/// RNG replaying a fixed secret, so `Ed25519KeyPair::generate` returns the session keypair
+/// the TypeScript generator imported.
+struct SecretReplayRng([u8; 32]);
+
+impl RngCore for SecretReplayRng {
+    fn next_u32(&mut self) -> u32 {
+        unimplemented!("Ed25519 key generation only calls fill_bytes")
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        unimplemented!("Ed25519 key generation only calls fill_bytes")
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        dest.copy_from_slice(&self.0[..dest.len()]);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for SecretReplayRng {}
+
+#[tokio::test]
+async fn test_fetch_key_request_vectors() -> anyhow::Result<()> {
+    let vectors: Vec<FetchKeyRequestVector> = serde_json::from_str(FETCH_KEY_REQUESTS)?;
+
+    for vector in vectors {
+        let expected = &vector.json;
+        let expected_certificate = &expected["certificate"];
+        let field = |value: &Value| BASE64.decode(value.as_str().unwrap());
+
+        // Same user key, session key, clock and PTB as the TypeScript generator.
+        let mut signer = KeypairSigner::new(Ed25519KeyPair::from(Ed25519PrivateKey::from_bytes(
+            &USER_SECRET_KEY,
+        )?));
+        let mut builder = SessionKey::builder(
+            ObjectID::from_str(&vector.package_id)?,
+            expected_certificate["ttl_min"].as_u64().unwrap() as u16,
+        )
+        .clock(FixedClock::new(
+            expected_certificate["creation_time"].as_u64().unwrap(),
+        ))
+        .rng(SecretReplayRng(SESSION_SECRET_KEY));
+        if let Some(mvr_name) = expected_certificate["mvr_name"].as_str() {
+            builder = builder.mvr_name(mvr_name);
+        }
+        let session_key = builder.build(&mut signer).await?;
+
+        let ptb = field(&expected["ptb"])?;
+        let (request, _) = session_key
+            .get_fetch_key_request_with_rng(ptb.clone(), &mut StdRng::seed_from_u64(0))?;
+        let json: Value = serde_json::from_str(&request.to_json_string()?)?;
+
+        // The certificate is deterministic, so the crate reproduces it byte for byte.
+        assert_eq!(
+            json["certificate"], *expected_certificate,
+            "{}",
+            vector.name
+        );
+        assert_eq!(json["ptb"], expected["ptb"], "{}", vector.name);
+        assert_eq!(
+            json.as_object().unwrap().keys().collect::<Vec<_>>(),
+            expected.as_object().unwrap().keys().collect::<Vec<_>>(),
+            "{}",
+            vector.name
+        );
+
+        // The ElGamal keys are random, so check the TypeScript request signature against the
+        // crate's encoding of the signed request instead.
+        let signed = signed_request(
+            ptb,
+            field(&expected["enc_key"])?,
+            field(&expected["enc_verification_key"])?,
+        )?;
+        request.certificate.session_vk.verify(
+            &signed,
+            &Ed25519Signature::from_bytes(&field(&expected["request_signature"])?)?,
+        )?;
+    }
+
+    Ok(())
+}
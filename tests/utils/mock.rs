@@ -0,0 +1,101 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mock key servers and an encrypt-then-decrypt round trip through them.
+
+use crate::utils::PRIVATE_KEY_BECH32;
+use seal_sdk_rs::base_client::KeyServerConfig;
+use seal_sdk_rs::error::SealClientError;
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::in_memory_sui_client::InMemorySuiClient;
+use seal_sdk_rs::mock_key_server::{MockHttpClient, MockKeyServer, seal_approve_transaction};
+use seal_sdk_rs::seal_api::SealApi;
+use seal_sdk_rs::session_key::SessionKey;
+use seal_sdk_rs::signer::keypair::KeypairSigner;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub const PACKAGE_ID: ObjectID = ObjectID([0xaa; 32]);
+pub const ID: [u8; 3] = [1, 2, 3];
+
+pub fn server_url(index: usize) -> String {
+    format!("https://key-server-{index}.example.com")
+}
+
+/// `count` mock key servers with ids `[1; 32]`, `[2; 32]`, ... served at [`server_url`].
+pub fn mock_servers(count: usize) -> MockHttpClient {
+    mock_servers_with(count, |_, server| server)
+}
+
+/// Same as [`mock_servers`], passing each server and its index through `configure`.
+pub fn mock_servers_with(
+    count: usize,
+    configure: impl Fn(usize, MockKeyServer) -> MockKeyServer,
+) -> MockHttpClient {
+    MockHttpClient::new((0..count).map(|index| {
+        Arc::new(configure(
+            index,
+            MockKeyServer::new(ObjectID([index as u8 + 1; 32]), server_url(index)),
+        ))
+    }))
+}
+
+pub fn key_server_configs(mock_servers: &MockHttpClient) -> Vec<KeyServerConfig> {
+    mock_servers
+        .servers()
+        .iter()
+        .map(|server| KeyServerConfig::new(server.object_id(), None))
+        .collect()
+}
+
+/// Sui client resolving the objects of `mock_servers`.
+pub fn sui_client(mock_servers: &MockHttpClient) -> InMemorySuiClient {
+    InMemorySuiClient::new().with_key_servers(
+        mock_servers
+            .servers()
+            .iter()
+            .map(|server| server.key_server_info()),
+    )
+}
+
+/// Encrypt `b"secret"` under [`ID`] with `threshold` out of every server of `mock_servers`,
+/// then decrypt it with `client`.
+pub async fn round_trip(
+    client: &dyn SealApi,
+    mock_servers: &MockHttpClient,
+    threshold: u8,
+) -> Result<Vec<u8>, SealClientError> {
+    let (encrypted, _recovery_key) = client
+        .encrypt_bytes(
+            PACKAGE_ID,
+            ID.to_vec(),
+            threshold,
+            key_server_configs(mock_servers),
+            b"secret".to_vec(),
+        )
+        .await?;
+
+    let mut signer = KeypairSigner::from_bech32(PRIVATE_KEY_BECH32).unwrap();
+    let session_key = SessionKey::new(PACKAGE_ID, None, 5, &mut signer).await?;
+    let approve_transaction = seal_approve_transaction(PACKAGE_ID, "policy", &[ID.to_vec()])?;
+
+    client
+        .decrypt_object_bytes(
+            &bcs::to_bytes(&encrypted)?,
+            &approve_transaction,
+            &session_key,
+            HashMap::new(),
+        )
+        .await
+}
@@ -12,4 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+// Each test crate uses its own subset of these helpers.
+#![allow(dead_code)]
+
+pub mod mock;
+#[cfg(feature = "native-sui-sdk")]
 pub mod setup;
+pub mod static_sui_client;
+
+pub const PRIVATE_KEY_BECH32: &str =
+    "suiprivkey1qqrswpc8qurswpc8qurswpc8qurswpc8qurswpc8qurswpc8qurswxzszc4";
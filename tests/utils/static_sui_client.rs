@@ -0,0 +1,67 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sui client resolving every id to an independent key server, without any network call.
+
+use async_trait::async_trait;
+use fastcrypto::groups::GroupElement;
+use fastcrypto::groups::bls12381::{G2Element, Scalar};
+use fastcrypto::serde_helpers::ToFromByteArray;
+use seal_sdk_rs::base_client::{KeyServerInfo, ServerType};
+use seal_sdk_rs::generic_types::ObjectID;
+use seal_sdk_rs::json_rpc::sui_client::JsonRpcSuiClientError;
+use seal_sdk_rs::sui_client::SuiClient;
+
+/// Package of the key servers returned by [`key_server_info`].
+pub const SEAL_PACKAGE: ObjectID = ObjectID([0x5e; 32]);
+
+/// Master key of the key server `key_server_id`, derived from its first byte.
+pub fn master_key(key_server_id: ObjectID) -> u128 {
+    key_server_id.0[0] as u128 + 1
+}
+
+pub fn public_key(master_key: u128) -> G2Element {
+    G2Element::generator() * Scalar::from(master_key)
+}
+
+pub fn key_server_url(key_server_id: ObjectID) -> String {
+    format!("https://server-{}.example.com", key_server_id.0[0])
+}
+
+pub fn key_server_info(key_server_id: ObjectID) -> KeyServerInfo {
+    KeyServerInfo {
+        object_id: key_server_id,
+        package_id: SEAL_PACKAGE,
+        name: format!("server-{}", key_server_id.0[0]),
+        public_key: hex::encode(public_key(master_key(key_server_id)).to_byte_array()),
+        server_type: ServerType::Independent {
+            url: key_server_url(key_server_id),
+        },
+    }
+}
+
+/// Resolves every id with [`key_server_info`].
+pub struct StaticSuiClient;
+
+#[async_trait]
+impl SuiClient for StaticSuiClient {
+    type Error = JsonRpcSuiClientError;
+
+    async fn get_key_server_info(
+        &self,
+        key_server_id: [u8; 32],
+    ) -> Result<KeyServerInfo, Self::Error> {
+        Ok(key_server_info(ObjectID(key_server_id)))
+    }
+}
@@ -0,0 +1,236 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Generates the vectors checked by `tests/ts_sdk_compat_tests.rs` with the TypeScript Seal SDK.
+//
+//     npm ci && PACKAGE_ID=0x... MVR_NAME=@org/app npm run generate
+//
+// Key server public keys and the package are read from Sui testnet. `PACKAGE_ID` must be the
+// first version of a package published on testnet, and `MVR_NAME` a Move Registry name
+// pointing to it.
+
+import { writeFileSync } from 'node:fs';
+import { DemType, EncryptedObject, SealClient, SessionKey } from '@mysten/seal';
+import { getFullnodeUrl, SuiClient } from '@mysten/sui/client';
+import { Ed25519Keypair } from '@mysten/sui/keypairs/ed25519';
+import { Transaction } from '@mysten/sui/transactions';
+import { fromHex, toBase64, toHex } from '@mysten/sui/utils';
+
+// Mysten Labs testnet key servers running in open mode.
+const KEY_SERVERS = [
+	'0x73d05d62c18d9374e3ea529e8e0ed6161da1a141a94d3f76ae3fe4e99356db75',
+	'0xf5d14a81a982144ae441cd7d64b09027f116a468bd36e7eca494f750591623c8',
+	'0x6068c0acb197dddbacd4746a9de7f025b2ed5a5b6c1b1ab44dade4426d141da2',
+];
+
+// Secret key of RFC 8032 test 1, so the user address is stable across runs.
+const USER_SECRET_KEY = fromHex('9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60');
+// Secret key of RFC 8032 test 2, imported as the session key so the Rust tests can rebuild the
+// same certificates.
+const SESSION_SECRET_KEY = fromHex('4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb');
+
+const packageId = required('PACKAGE_ID');
+const mvrName = required('MVR_NAME');
+
+const suiClient = new SuiClient({ url: getFullnodeUrl('testnet') });
+
+function required(name: string): string {
+	const value = process.env[name];
+	if (!value) {
+		throw new Error(`${name} is not set`);
+	}
+	return value;
+}
+
+function hex(value: Uint8Array | number[] | string): string {
+	if (typeof value === 'string') {
+		return value.replace(/^0x/, '');
+	}
+	return toHex(Uint8Array.from(value));
+}
+
+function sealClient(serverConfigs: { objectId: string; weight: number }[]): SealClient {
+	return new SealClient({ suiClient, serverConfigs, verifyKeyServers: false });
+}
+
+function bytes(length: number): Uint8Array {
+	return Uint8Array.from({ length }, (_, index) => index % 256);
+}
+
+// Decoded fields of an `EncryptedObject`, in the layout read by the Rust tests.
+function describe(name: string, encoded: Uint8Array) {
+	const parsed = EncryptedObject.parse(encoded);
+
+	// The TS encoding must be canonical, so the Rust side can compare bytes.
+	if (toHex(EncryptedObject.serialize(parsed).toBytes()) !== toHex(encoded)) {
+		throw new Error(`${name} does not round-trip through the TS BCS schema`);
+	}
+
+	const shares = parsed.encryptedShares.BonehFranklinBLS12381!;
+	const ciphertext = parsed.ciphertext;
+	const aad = (value: Uint8Array | number[] | null | undefined) =>
+		value === null || value === undefined ? null : hex(value);
+
+	return {
+		name,
+		bcs: toHex(encoded),
+		version: parsed.version,
+		package_id: parsed.packageId,
+		id: hex(parsed.id),
+		services: parsed.services.map(([objectId, index]) => [objectId, index]),
+		threshold: parsed.threshold,
+		nonce: hex(shares.nonce),
+		encrypted_shares: shares.encryptedShares.map(hex),
+		encrypted_randomness: hex(shares.encryptedRandomness),
+		ciphertext: ciphertext.Aes256Gcm
+			? { type: 'Aes256Gcm', blob: hex(ciphertext.Aes256Gcm.blob), aad: aad(ciphertext.Aes256Gcm.aad) }
+			: ciphertext.Hmac256Ctr
+				? {
+						type: 'Hmac256Ctr',
+						blob: hex(ciphertext.Hmac256Ctr.blob),
+						aad: aad(ciphertext.Hmac256Ctr.aad),
+						mac: hex(ciphertext.Hmac256Ctr.mac),
+					}
+				: { type: 'Plain' },
+	};
+}
+
+async function encryptedObjects() {
+	const encrypt = async (
+		name: string,
+		serverConfigs: { objectId: string; weight: number }[],
+		options: { threshold: number; id: string; data: Uint8Array; aad?: Uint8Array; demType?: DemType },
+	) => {
+		const { encryptedObject } = await sealClient(serverConfigs).encrypt({ packageId, ...options });
+		return describe(name, encryptedObject);
+	};
+	const servers = (count: number) => KEY_SERVERS.slice(0, count).map((objectId) => ({ objectId, weight: 1 }));
+
+	const aes = await encrypt('aes_2_of_3', servers(3), {
+		threshold: 2,
+		id: '0102030405',
+		data: bytes(16),
+	});
+	const aesWithAad = await encrypt('aes_with_aad_same_server_twice', [{ objectId: KEY_SERVERS[0], weight: 2 }], {
+		threshold: 1,
+		id: hex(new TextEncoder().encode('seal')),
+		data: bytes(24),
+		aad: new TextEncoder().encode('aad'),
+	});
+	const hmac = await encrypt('hmac_ctr', servers(1), {
+		threshold: 1,
+		id: '',
+		data: bytes(200),
+		demType: DemType.Hmac256Ctr,
+	});
+
+	// The TS SDK never produces `Plain` ciphertexts, so re-encode a TS object with one to cover
+	// the variant with the TS schema.
+	const { encryptedObject } = await sealClient(servers(2)).encrypt({
+		packageId,
+		threshold: 2,
+		id: hex(bytes(10)),
+		data: bytes(1),
+	});
+	const parsed = EncryptedObject.parse(encryptedObject);
+	const plain = describe(
+		'plain',
+		EncryptedObject.serialize({ ...parsed, ciphertext: { Plain: true } }).toBytes(),
+	);
+
+	return [aes, aesWithAad, hmac, plain];
+}
+
+async function sessionKey(mvrName?: string) {
+	const signer = Ed25519Keypair.fromSecretKey(USER_SECRET_KEY);
+	const sessionKey = SessionKey.import(
+		{
+			address: signer.toSuiAddress(),
+			packageId,
+			mvrName,
+			ttlMin: 10,
+			creationTimeMs: Date.now(),
+			sessionKey: Ed25519Keypair.fromSecretKey(SESSION_SECRET_KEY).getSecretKey(),
+		},
+		suiClient,
+	);
+	const { signature } = await signer.signPersonalMessage(sessionKey.getPersonalMessage());
+	await sessionKey.setPersonalMessageSignature(signature);
+	const exported = sessionKey.export();
+
+	return {
+		sessionKey,
+		vector: {
+			package_name: mvrName ?? packageId,
+			session_vk: toBase64(
+				Ed25519Keypair.fromSecretKey(exported.sessionKey).getPublicKey().toRawBytes(),
+			),
+			creation_time: exported.creationTimeMs,
+			ttl_min: exported.ttlMin,
+			message: new TextDecoder().decode(sessionKey.getPersonalMessage()),
+		},
+	};
+}
+
+// Capture the `/v1/fetch_key` body the TS SDK sends, without reaching the key server.
+async function fetchKeyRequest(name: string, key: SessionKey) {
+	const tx = new Transaction();
+	tx.moveCall({
+		target: `${packageId}::policy::seal_approve`,
+		arguments: [tx.pure.vector('u8', [1, 2, 3])],
+	});
+	const txBytes = await tx.build({ client: suiClient, onlyTransactionKind: true });
+
+	let body: string | undefined;
+	const realFetch = globalThis.fetch;
+	globalThis.fetch = async (input, init) => {
+		if (String(input).includes('/v1/fetch_key')) {
+			body = String(init?.body);
+			return new Response('{"error":"Captured"}', { status: 503 });
+		}
+		return realFetch(input, init);
+	};
+
+	try {
+		await sealClient(KEY_SERVERS.slice(0, 1).map((objectId) => ({ objectId, weight: 1 }))).fetchKeys({
+			ids: ['010203'],
+			txBytes,
+			sessionKey: key,
+			threshold: 1,
+		});
+	} catch {
+		// The captured request is answered with an error on purpose.
+	} finally {
+		globalThis.fetch = realFetch;
+	}
+
+	if (!body) {
+		throw new Error(`${name}: no fetch key request was sent`);
+	}
+
+	return { name, package_id: packageId, json: JSON.parse(body) };
+}
+
+const withoutMvr = await sessionKey();
+const withMvr = await sessionKey(mvrName);
+
+const write = (file: string, value: unknown) =>
+	writeFileSync(new URL(file, import.meta.url), `${JSON.stringify(value, null, 2)}\n`);
+
+write('encrypted_objects.json', await encryptedObjects());
+write('signed_messages.json', [withoutMvr.vector, withMvr.vector]);
+write('fetch_key_requests.json', [
+	await fetchKeyRequest('without_mvr_name', withoutMvr.sessionKey),
+	await fetchKeyRequest('with_mvr_name', withMvr.sessionKey),
+]);
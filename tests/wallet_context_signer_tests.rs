@@ -0,0 +1,124 @@
+// Copyright 2025 Quentin Diebold
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "native-sui-sdk")]
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use seal_sdk_rs::error::SessionKeyError;
+use seal_sdk_rs::generic_types::{ObjectID, SuiAddress};
+use seal_sdk_rs::native_sui_sdk::signer::wallet_context::{
+    WalletContextError, WalletContextSigner,
+};
+use seal_sdk_rs::session_key::SessionKey;
+use seal_sdk_rs::signer::Signer;
+use sui_keys::keystore::{AccountKeystore, InMemKeystore, Keystore};
+use sui_sdk::wallet_context::WalletContext;
+use sui_types::crypto::{Secp256k1KeyPair, SuiKeyPair, get_key_pair_from_rng};
+
+const PACKAGE_ID: ObjectID = ObjectID([1; 32]);
+
+fn wallet_with_ed25519_keys(count: usize) -> WalletContext {
+    let keystore = Keystore::InMem(InMemKeystore::new_insecure_for_tests(count));
+    WalletContext::new_for_tests(keystore, None, None)
+}
+
+/// Addresses of the wallet's keystore, the active one first.
+fn addresses(wallet: &WalletContext) -> anyhow::Result<Vec<SuiAddress>> {
+    let active = wallet.active_address()?;
+    let mut addresses = vec![active];
+    addresses.extend(
+        wallet
+            .get_addresses()
+            .into_iter()
+            .filter(|address| *address != active),
+    );
+
+    Ok(addresses
+        .into_iter()
+        .map(|address| SuiAddress(address.to_inner()))
+        .collect())
+}
+
+#[tokio::test]
+async fn test_wallet_context_signer_signs_for_inactive_address() -> anyhow::Result<()> {
+    let wallet = wallet_with_ed25519_keys(2);
+    let addresses = addresses(&wallet)?;
+    let inactive = addresses[1];
+
+    let mut signer = WalletContextSigner::new(&wallet, inactive);
+    assert_eq!(signer.get_sui_address()?, inactive);
+
+    // The session key builder checks that the public key derives to the claimed address, so
+    // this only succeeds when the signer used the inactive address' key.
+    let session_key = SessionKey::new(PACKAGE_ID, None, 5, &mut signer).await?;
+    assert_eq!(session_key.address(), &inactive);
+
+    assert_eq!(
+        SuiAddress(wallet.active_address()?.to_inner()),
+        addresses[0]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_wallet_context_signer_rejects_non_ed25519_keys() -> anyhow::Result<()> {
+    let (address, keypair) =
+        get_key_pair_from_rng::<Secp256k1KeyPair, _>(&mut StdRng::from_seed([0; 32]));
+
+    let mut keystore = Keystore::InMem(InMemKeystore::new_insecure_for_tests(0));
+    keystore
+        .import(None, SuiKeyPair::Secp256k1(keypair))
+        .await?;
+    let wallet = WalletContext::new_for_tests(keystore, None, None);
+
+    let mut signer = WalletContextSigner::new(&wallet, SuiAddress(address.to_inner()));
+
+    assert!(matches!(
+        signer.get_public_key(),
+        Err(WalletContextError::IncorrectSignatureScheme)
+    ));
+    assert!(matches!(
+        signer.sign_personal_message(b"hello seal".to_vec()).await,
+        Err(WalletContextError::IncorrectSignatureScheme)
+    ));
+    assert!(matches!(
+        SessionKey::new(PACKAGE_ID, None, 5, &mut signer).await,
+        Err(SessionKeyError::WalletContext(
+            WalletContextError::IncorrectSignatureScheme
+        ))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_wallet_context_signers_share_wallet_concurrently() -> anyhow::Result<()> {
+    let wallet = wallet_with_ed25519_keys(2);
+    let addresses = addresses(&wallet)?;
+
+    let mut first_signer = WalletContextSigner::new(&wallet, addresses[0]);
+    let mut second_signer = WalletContextSigner::new(&wallet, addresses[1]);
+
+    let (first_session_key, second_session_key) = futures::try_join!(
+        SessionKey::new(PACKAGE_ID, None, 5, &mut first_signer),
+        SessionKey::new(PACKAGE_ID, None, 5, &mut second_signer),
+    )?;
+
+    assert_eq!(first_session_key.address(), &addresses[0]);
+    assert_eq!(second_session_key.address(), &addresses[1]);
+
+    Ok(())
+}